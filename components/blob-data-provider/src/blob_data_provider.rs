@@ -0,0 +1,243 @@
+use crate::error::Error;
+use crate::schema::{self, BlobIndex};
+use bincode::Options;
+use icu_data_provider::iter::DataEntryCollection;
+use icu_data_provider::prelude::*;
+use icu_data_provider::structs;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::convert::TryInto;
+use std::fmt;
+use std::ops::Range;
+
+/// A data provider that reads ICU4X data from a single data blob.
+///
+/// The blob is indexed once on construction; each call to `load` then deserializes only the
+/// payload being requested.
+///
+/// The provider either owns the blob or borrows it for the lifetime `'d`, such as from a
+/// memory-mapped file owned by the caller. If the blob is borrowed, strings in the payloads
+/// borrow from it for the lifetime `'d` instead of being copied on every load; read them without
+/// copying through [`TypedDataProvider::load_typed`].
+///
+/// # Example
+///
+/// ```
+/// use icu_blob_data_provider::BlobDataProvider;
+///
+/// // For example, a blob embedded at build time:
+/// // static BLOB: &[u8] = include_bytes!("icu4x_data.blob");
+/// static BLOB: &[u8] = b"not a data blob";
+///
+/// assert!(BlobDataProvider::try_new_from_static_blob(BLOB).is_err());
+/// ```
+pub struct BlobDataProvider<'d> {
+    blob: Blob<'d>,
+    payloads: HashMap<DataRequest, Range<usize>>,
+    entries: HashMap<DataKey, Vec<DataEntry>>,
+    data_version: Option<String>,
+}
+
+/// The bytes of a data blob.
+enum Blob<'d> {
+    /// A blob borrowed from the caller, such as a memory-mapped file, from which payloads can
+    /// borrow their strings.
+    Borrowed(&'d [u8]),
+    Owned(Vec<u8>),
+}
+
+impl Blob<'_> {
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Blob::Borrowed(blob) => blob,
+            Blob::Owned(blob) => blob,
+        }
+    }
+}
+
+impl fmt::Debug for BlobDataProvider<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BlobDataProvider")
+            .field("blob_len", &self.blob.as_slice().len())
+            .field("entries", &self.entries)
+            .field("data_version", &self.data_version)
+            .finish()
+    }
+}
+
+impl<'d> BlobDataProvider<'d> {
+    /// Create a new BlobDataProvider from a blob in static memory, such as one embedded with
+    /// `include_bytes!`. The blob is not copied, and strings in the payloads borrow from the blob
+    /// instead of being allocated on every load.
+    pub fn try_new_from_static_blob(blob: &'static [u8]) -> Result<Self, Error> {
+        Self::try_new(Blob::Borrowed(blob))
+    }
+
+    /// Create a new BlobDataProvider from a blob borrowed for the lifetime `'d`, such as a
+    /// memory-mapped file. The blob is not copied, and strings in the payloads borrow from the
+    /// blob instead of being allocated on every load.
+    pub fn try_new_from_borrowed_blob(blob: &'d [u8]) -> Result<Self, Error> {
+        Self::try_new(Blob::Borrowed(blob))
+    }
+
+    /// Create a new BlobDataProvider from a memory-mapped blob file. Requires the `mmap` feature.
+    ///
+    /// The caller owns the mapping, which must outlive the provider and the payloads it returns.
+    /// Only pages that are read are loaded into memory. See
+    /// [`try_new_from_borrowed_blob`](Self::try_new_from_borrowed_blob).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use icu_blob_data_provider::BlobDataProvider;
+    ///
+    /// let file = std::fs::File::open("icu4x_data.blob").unwrap();
+    /// // Safety: the file is not modified while it is mapped.
+    /// let mmap = unsafe { memmap2::Mmap::map(&file) }.unwrap();
+    /// let provider = BlobDataProvider::try_new_from_mmap(&mmap).unwrap();
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub fn try_new_from_mmap(mmap: &'d memmap2::Mmap) -> Result<Self, Error> {
+        Self::try_new_from_borrowed_blob(mmap)
+    }
+
+    /// Create a new BlobDataProvider from a blob in an owned buffer, such as one read from a
+    /// file at runtime.
+    pub fn try_new_from_blob(blob: Vec<u8>) -> Result<Self, Error> {
+        Self::try_new(Blob::Owned(blob))
+    }
+
+    fn try_new(blob: Blob<'d>) -> Result<Self, Error> {
+        let bytes = blob.as_slice();
+        if bytes.len() < schema::HEADER_LEN || &bytes[..8] != schema::MAGIC {
+            return Err(Error::InvalidHeader);
+        }
+        let format_version = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        if format_version != schema::FORMAT_VERSION {
+            return Err(Error::UnsupportedFormatVersion(format_version));
+        }
+        let index_len = u64::from_le_bytes(bytes[12..20].try_into().unwrap());
+        let payloads_start = get_end(schema::HEADER_LEN, index_len, bytes.len())?;
+        let index: BlobIndex =
+            schema::bincode_options().deserialize(&bytes[schema::HEADER_LEN..payloads_start])?;
+
+        let mut payloads = HashMap::with_capacity(index.entries.len());
+        let mut entries: HashMap<DataKey, Vec<DataEntry>> = HashMap::new();
+        for index_entry in index.entries {
+            let start = get_end(payloads_start, index_entry.offset, bytes.len())?;
+            let end = get_end(start, index_entry.length, bytes.len())?;
+            let req = DataRequest {
+                data_key: index_entry.data_key.parse()?,
+                data_entry: index_entry.data_entry.parse()?,
+            };
+            entries
+                .entry(req.data_key)
+                .or_default()
+                .push(req.data_entry.clone());
+            payloads.insert(req, start..end);
+        }
+        Ok(Self {
+            blob,
+            payloads,
+            entries,
+            data_version: index.data_version,
+        })
+    }
+
+    /// Returns the error for a DataKey that is not in the blob.
+    fn unsupported_key_error(&self, data_key: &DataKey) -> DataError {
+        if self
+            .entries
+            .keys()
+            .any(|other| other.category == data_key.category)
+        {
+            DataError::UnsupportedDataKey(*data_key)
+        } else {
+            DataError::UnsupportedCategory(data_key.category)
+        }
+    }
+}
+
+/// Adds a length from the index to a position in the blob, checking that the result is within
+/// the blob.
+fn get_end(start: usize, length: u64, blob_len: usize) -> Result<usize, Error> {
+    usize::try_from(length)
+        .ok()
+        .and_then(|length| start.checked_add(length))
+        .filter(|end| *end <= blob_len)
+        .ok_or(Error::Truncated)
+}
+
+impl<'d> DataProvider<'d> for BlobDataProvider<'d> {
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, DataError> {
+        let range = match self.payloads.get(req) {
+            Some(range) => range.clone(),
+            None if self.entries.contains_key(&req.data_key) => {
+                return Err(DataError::UnavailableEntry(req.clone()))
+            }
+            None => return Err(self.unsupported_key_error(&req.data_key)),
+        };
+        let builder = DataResponseBuilder {
+            data_langid: req.data_entry.langid.clone(),
+        };
+        let mut response = match self.blob {
+            // A borrowed blob outlives the response, so the payload can borrow from it.
+            Blob::Borrowed(blob) => {
+                let mut deserializer =
+                    bincode::Deserializer::from_slice(&blob[range], schema::bincode_options());
+                structs::deserialize_borrowed_response(
+                    &req.data_key,
+                    builder,
+                    &mut <dyn erased_serde::Deserializer>::erase(&mut deserializer),
+                )?
+            }
+            Blob::Owned(ref blob) => {
+                let mut deserializer =
+                    bincode::Deserializer::from_slice(&blob[range], schema::bincode_options());
+                structs::deserialize_response(
+                    &req.data_key,
+                    builder,
+                    &mut <dyn erased_serde::Deserializer>::erase(&mut deserializer),
+                )?
+            }
+        };
+        response.metadata.data_version = self.data_version.clone().map(Cow::Owned);
+        Ok(response)
+    }
+}
+
+impl DataEntryCollection for BlobDataProvider<'_> {
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {
+        match self.entries.get(data_key) {
+            Some(entries) => Ok(Box::new(entries.clone().into_iter())),
+            None => Err(self.unsupported_key_error(data_key)),
+        }
+    }
+}
+
+#[test]
+fn test_invalid_blob() {
+    let mut blob = schema::MAGIC.to_vec();
+    assert!(matches!(
+        BlobDataProvider::try_new_from_blob(blob.clone()),
+        Err(Error::InvalidHeader)
+    ));
+
+    blob.extend_from_slice(&2u32.to_le_bytes());
+    blob.extend_from_slice(&0u64.to_le_bytes());
+    assert!(matches!(
+        BlobDataProvider::try_new_from_blob(blob.clone()),
+        Err(Error::UnsupportedFormatVersion(2))
+    ));
+
+    blob[8..12].copy_from_slice(&schema::FORMAT_VERSION.to_le_bytes());
+    blob[12..20].copy_from_slice(&100u64.to_le_bytes());
+    assert!(matches!(
+        BlobDataProvider::try_new_from_blob(blob),
+        Err(Error::Truncated)
+    ));
+}
@@ -0,0 +1,61 @@
+use icu_data_provider::ParseError;
+use std::fmt;
+
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Error {
+    /// The blob does not start with the ICU4X data blob header.
+    InvalidHeader,
+    /// The blob was written with an incompatible version of the blob layout.
+    UnsupportedFormatVersion(u32),
+    /// The index refers to bytes past the end of the blob.
+    Truncated,
+    /// A DataKey or DataEntry in the index could not be parsed.
+    ParseError(ParseError),
+    BincodeError(bincode::Error),
+    IoError(std::io::Error),
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Error {
+        Error::ParseError(err)
+    }
+}
+
+impl From<bincode::Error> for Error {
+    fn from(err: bincode::Error) -> Error {
+        Error::BincodeError(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::IoError(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidHeader => write!(f, "Not an ICU4X data blob"),
+            Error::UnsupportedFormatVersion(version) => {
+                write!(f, "Unsupported data blob format version: {}", version)
+            }
+            Error::Truncated => write!(f, "Data blob is truncated"),
+            Error::ParseError(error) => write!(f, "{}", error),
+            Error::BincodeError(error) => write!(f, "{}", error),
+            Error::IoError(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ParseError(error) => Some(error),
+            Error::BincodeError(error) => Some(error),
+            Error::IoError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! The `export` feature enables you to pull all data from some other data provider and persist it
+//! as a single data blob to be read by a BlobDataProvider at runtime.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_blob_data_provider::export::{BlobExporter, BlobExporterOptions};
+//! use icu_blob_data_provider::BlobDataProvider;
+//! use icu_data_provider::prelude::*;
+//! use icu_data_provider::structs::plurals::{self, PluralRuleStringsV1};
+//! use icu_data_provider::iter::IterableDataProvider;
+//! use icu_data_provider::InvariantDataProvider;
+//!
+//! let DATA_KEY = plurals::key::CARDINAL_V1;
+//!
+//! // Export a key into an in-memory buffer
+//! let mut exporter = BlobExporter::new(Vec::new(), &BlobExporterOptions::default());
+//! let inv_provider = InvariantDataProvider;
+//! inv_provider
+//!     .export_key(&DATA_KEY, &mut exporter)
+//!     .expect("Should successfully export");
+//! let blob: Vec<u8> = exporter.close().expect("Should successfully write the blob");
+//!
+//! // Create a blob provider reading from the buffer
+//! let blob_provider = BlobDataProvider::try_new_from_blob(blob)
+//!     .expect("Should successfully read the blob");
+//!
+//! // Read the key from the blob and ensure it is as expected
+//! let req = DataRequest {
+//!     data_key: DATA_KEY,
+//!     data_entry: DataEntry {
+//!         variant: None,
+//!         langid: "und".parse().unwrap(),
+//!     }
+//! };
+//! let inv_response = inv_provider.load(&req).unwrap();
+//! let blob_response = blob_provider.load(&req)
+//!     .expect("Should successfully read from the blob");
+//!
+//! assert_eq!(
+//!     inv_response.borrow_payload::<PluralRuleStringsV1>().unwrap(),
+//!     blob_response.borrow_payload::<PluralRuleStringsV1>().unwrap(),
+//! );
+//! ```
+
+use crate::error::Error;
+use crate::schema::{self, BlobIndex, BlobIndexEntry};
+use bincode::Options;
+use icu_data_provider::iter::DataExporter;
+use icu_data_provider::prelude::*;
+use std::io::Write;
+
+/// Options bag for initializing a BlobExporter.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct BlobExporterOptions {
+    /// Version of the exported data, such as the CLDR version, to record in the blob.
+    pub data_version: Option<String>,
+}
+
+/// A data exporter that writes all data to a single data blob.
+///
+/// The payloads are buffered in memory, since the index is written before them. Call
+/// [`close`](BlobExporter::close) after exporting all keys to write the blob to the sink.
+pub struct BlobExporter<W: Write> {
+    sink: W,
+    index: BlobIndex,
+    payloads: Vec<u8>,
+}
+
+impl<W: Write> DataExporter for BlobExporter<W> {
+    fn put(
+        &mut self,
+        req: &DataRequest,
+        obj: &dyn erased_serde::Serialize,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let offset = self.payloads.len();
+        schema::bincode_options().serialize_into(&mut self.payloads, obj)?;
+        self.index.entries.push(BlobIndexEntry {
+            data_key: req.data_key.to_string(),
+            data_entry: req.data_entry.to_string(),
+            offset: offset as u64,
+            length: (self.payloads.len() - offset) as u64,
+        });
+        Ok(())
+    }
+}
+
+impl<W: Write> BlobExporter<W> {
+    pub fn new(sink: W, options: &BlobExporterOptions) -> Self {
+        Self {
+            sink,
+            index: BlobIndex {
+                data_version: options.data_version.clone(),
+                entries: vec![],
+            },
+            payloads: vec![],
+        }
+    }
+
+    /// Writes the data blob to the sink, and returns the sink.
+    pub fn close(mut self) -> Result<W, Error> {
+        // Sort the index so that the output does not depend on the order of export.
+        self.index
+            .entries
+            .sort_by(|a, b| (&a.data_key, &a.data_entry).cmp(&(&b.data_key, &b.data_entry)));
+        let index_bytes = schema::bincode_options().serialize(&self.index)?;
+        self.sink.write_all(schema::MAGIC)?;
+        self.sink.write_all(&schema::FORMAT_VERSION.to_le_bytes())?;
+        self.sink
+            .write_all(&(index_bytes.len() as u64).to_le_bytes())?;
+        self.sink.write_all(&index_bytes)?;
+        self.sink.write_all(&self.payloads)?;
+        self.sink.flush()?;
+        Ok(self.sink)
+    }
+}
@@ -0,0 +1,34 @@
+//! `icu-blob-data-provider` is one of the [`ICU4X`] components.
+//!
+//! It reads ICU4X data from a single binary blob containing an index of data requests along with
+//! their payloads. A blob can be embedded in the binary as a `&'static [u8]`, for example with
+//! `include_bytes!`, or loaded into an owned buffer at runtime. This avoids shipping thousands of
+//! small data files, which is useful for mobile and other bundled applications.
+//!
+//! A blob can also be borrowed from the caller, such as a file memory-mapped with the `mmap`
+//! feature, so that it is not read into memory up front. When the blob is in static memory or
+//! borrowed, strings in the payloads borrow from the blob for the lifetime of the borrow, so
+//! loading data does not copy them. This is the only data provider that offers such zero-copy
+//! loading; `FsDataProvider` always copies the data it reads.
+//!
+//! Blobs are written by the `BlobExporter` in the `export` module, which requires the `export`
+//! feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_blob_data_provider::BlobDataProvider;
+//!
+//! let provider = BlobDataProvider::try_new_from_blob(vec![1, 2, 3])
+//!     .expect_err("Load a real data blob in the line above");
+//! ```
+
+mod blob_data_provider;
+mod error;
+mod schema;
+
+#[cfg(feature = "export")]
+pub mod export;
+
+pub use blob_data_provider::BlobDataProvider;
+pub use error::Error as BlobDataError;
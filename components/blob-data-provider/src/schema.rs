@@ -0,0 +1,46 @@
+//! Layout of a data blob:
+//!
+//! 1. [`MAGIC`], identifying the file as an ICU4X data blob.
+//! 2. [`FORMAT_VERSION`] as a little-endian `u32`.
+//! 3. The length in bytes of the index as a little-endian `u64`.
+//! 4. The [`BlobIndex`], serialized with bincode.
+//! 5. The payloads, each serialized with bincode, at the offsets recorded in the index relative
+//!    to the end of the index.
+
+use serde::{Deserialize, Serialize};
+
+/// Bytes at the start of every data blob.
+pub(crate) const MAGIC: &[u8; 8] = b"ICU4XBLB";
+
+/// Version of the blob layout. Incremented on incompatible changes.
+pub(crate) const FORMAT_VERSION: u32 = 1;
+
+/// Length of the header preceding the index: the magic bytes, the format version, and the index
+/// length.
+pub(crate) const HEADER_LEN: usize = 8 + 4 + 8;
+
+/// Index of all payloads in a data blob.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BlobIndex {
+    /// The version of the exported data, such as the CLDR version it was derived from.
+    pub data_version: Option<String>,
+    pub entries: Vec<BlobIndexEntry>,
+}
+
+/// Location of a single payload in a data blob.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BlobIndexEntry {
+    /// The DataKey in string form, such as "plurals/cardinal@1".
+    pub data_key: String,
+    /// The DataEntry in string form, such as "sr-Latn".
+    pub data_entry: String,
+    /// Offset of the payload relative to the start of the payloads section.
+    pub offset: u64,
+    /// Length of the payload in bytes.
+    pub length: u64,
+}
+
+/// The bincode configuration used for both the index and the payloads.
+pub(crate) fn bincode_options() -> bincode::DefaultOptions {
+    bincode::DefaultOptions::new()
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "export")]
+
+use icu_blob_data_provider::export::{BlobExporter, BlobExporterOptions};
+use icu_blob_data_provider::BlobDataProvider;
+use icu_data_provider::iter::{DataEntryCollection, DataExporter, IterableDataProvider};
+use icu_data_provider::prelude::*;
+use icu_data_provider::structs;
+use icu_data_provider::InvariantDataProvider;
+use std::borrow::Cow;
+
+fn export_all_keys() -> Vec<u8> {
+    let mut options = BlobExporterOptions::default();
+    options.data_version = Some("37".to_string());
+    let mut exporter = BlobExporter::new(Vec::new(), &options);
+    for data_key in structs::get_all_keys() {
+        InvariantDataProvider
+            .export_key(&data_key, &mut exporter)
+            .expect("Should successfully export");
+    }
+    exporter
+        .close()
+        .expect("Should successfully write the blob")
+}
+
+fn make_request(data_key: DataKey, langid: &str) -> DataRequest {
+    DataRequest {
+        data_key,
+        data_entry: DataEntry {
+            variant: None,
+            langid: langid.parse().expect("Valid language tag"),
+        },
+    }
+}
+
+#[test]
+fn test_round_trip_all_keys() {
+    let provider = BlobDataProvider::try_new_from_blob(export_all_keys())
+        .expect("Should successfully read the blob");
+    for data_key in structs::get_all_keys() {
+        let req = make_request(data_key, "und");
+        let expected = InvariantDataProvider.load(&req).unwrap();
+        let actual = provider
+            .load(&req)
+            .expect("Every key in the registry should be readable");
+        assert_eq!(
+            serde_json::to_string(expected.borrow_as_serialize()).unwrap(),
+            serde_json::to_string(actual.borrow_as_serialize()).unwrap(),
+            "{}",
+            data_key
+        );
+        assert_eq!(actual.metadata.data_version.as_deref(), Some("37"));
+
+        let entries: Vec<DataEntry> = provider.iter_for_key(&data_key).unwrap().collect();
+        assert_eq!(entries, vec![req.data_entry]);
+    }
+}
+
+#[test]
+fn test_static_blob() {
+    let blob: &'static [u8] = Box::leak(export_all_keys().into_boxed_slice());
+    let provider = BlobDataProvider::try_new_from_static_blob(blob)
+        .expect("Should successfully read the blob");
+    let response = provider
+        .load(&make_request(structs::plurals::key::CARDINAL_V1, "und"))
+        .unwrap();
+    let _: &structs::plurals::PluralRuleStringsV1 = response.borrow_payload().unwrap();
+}
+
+/// Exports plural rules with strings, which can be borrowed from the blob.
+fn export_plural_rules() -> Vec<u8> {
+    let mut exporter = BlobExporter::new(Vec::new(), &BlobExporterOptions::default());
+    let rules = structs::plurals::PluralRuleStringsV1 {
+        zero: None,
+        one: Some(Cow::Owned("i = 1 and v = 0".to_string())),
+        two: None,
+        few: None,
+        many: None,
+    };
+    exporter
+        .put(
+            &make_request(structs::plurals::key::CARDINAL_V1, "en"),
+            &rules,
+        )
+        .expect("Should successfully export");
+    exporter
+        .close()
+        .expect("Should successfully write the blob")
+}
+
+fn check_rule_for_one(provider: &BlobDataProvider, borrowed: bool) {
+    let response = provider
+        .load_typed::<structs::plurals::CardinalV1Marker>(
+            &make_request(structs::plurals::key::CARDINAL_V1, "en").data_entry,
+        )
+        .unwrap();
+    let rules = response.get();
+    assert_eq!(rules.one.as_deref(), Some("i = 1 and v = 0"));
+    assert_eq!(matches!(rules.one, Some(Cow::Borrowed(_))), borrowed);
+}
+
+#[test]
+fn test_zero_copy() {
+    let blob = export_plural_rules();
+    let provider = BlobDataProvider::try_new_from_blob(blob.clone()).unwrap();
+    check_rule_for_one(&provider, false);
+
+    let provider = BlobDataProvider::try_new_from_borrowed_blob(&blob).unwrap();
+    check_rule_for_one(&provider, true);
+
+    let blob: &'static [u8] = Box::leak(blob.into_boxed_slice());
+    let provider = BlobDataProvider::try_new_from_static_blob(blob).unwrap();
+    check_rule_for_one(&provider, true);
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_mmap() {
+    let path = std::env::temp_dir().join("icu4x_mmap_test.blob");
+    std::fs::write(&path, export_plural_rules()).unwrap();
+    let file = std::fs::File::open(&path).unwrap();
+    // Safety: the test file is not modified while it is mapped.
+    let mmap = unsafe { memmap2::Mmap::map(&file) }.unwrap();
+    let provider = BlobDataProvider::try_new_from_mmap(&mmap).unwrap();
+    check_rule_for_one(&provider, true);
+    drop(provider);
+    drop(mmap);
+    std::fs::remove_file(&path).expect("Should clean up test file");
+}
+
+#[test]
+fn test_errors() {
+    let mut exporter = BlobExporter::new(Vec::new(), &BlobExporterOptions::default());
+    InvariantDataProvider
+        .export_key(&structs::plurals::key::CARDINAL_V1, &mut exporter)
+        .unwrap();
+    let provider = BlobDataProvider::try_new_from_blob(exporter.close().unwrap()).unwrap();
+
+    assert!(matches!(
+        provider.load(&make_request(structs::plurals::key::CARDINAL_V1, "sr")),
+        Err(DataError::UnavailableEntry(_))
+    ));
+    assert!(matches!(
+        provider.load(&make_request(structs::plurals::key::ORDINAL_V1, "und")),
+        Err(DataError::UnsupportedDataKey(_))
+    ));
+    assert!(matches!(
+        provider.load(&make_request(structs::decimal::key::SYMBOLS_V1, "und")),
+        Err(DataError::UnsupportedCategory(_))
+    ));
+    assert!(matches!(
+        provider.iter_for_key(&structs::plurals::key::ORDINAL_V1),
+        Err(DataError::UnsupportedDataKey(_))
+    ));
+}
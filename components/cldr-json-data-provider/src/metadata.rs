@@ -0,0 +1,23 @@
+use icu_data_provider::prelude::*;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::path::Path;
+
+/// The "version" object found in the "supplemental" section of CLDR JSON files.
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct CldrVersion {
+    #[serde(rename = "_cldrVersion")]
+    pub cldr_version: String,
+}
+
+/// Builds the metadata for responses derived from a CLDR JSON file. The path is not known if the
+/// file was parsed from a string.
+pub(crate) fn make_metadata(
+    path: Option<&Path>,
+    version: Option<&CldrVersion>,
+) -> DataResponseMetadata {
+    let mut metadata = DataResponseMetadata::default();
+    metadata.source = path.map(|path| Cow::Owned(path.to_string_lossy().into_owned()));
+    metadata.data_version = version.map(|version| Cow::Owned(version.cldr_version.clone()));
+    metadata
+}
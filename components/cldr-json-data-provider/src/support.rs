@@ -29,7 +29,7 @@ fn map_poison<E>(_err: E) -> DataError {
 impl<'b, 'd, T> LazyCldrProvider<T>
 where
     T: DataProvider<'d> + DataKeySupport + DataEntryCollection + TryFrom<&'b CldrPaths>,
-    <T as TryFrom<&'b CldrPaths>>::Error: 'static + std::error::Error,
+    <T as TryFrom<&'b CldrPaths>>::Error: 'static + std::error::Error + Send + Sync,
 {
     /// Call T::load, initializing T if necessary.
     pub fn try_load(
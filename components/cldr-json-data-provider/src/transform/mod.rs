@@ -1,5 +1,7 @@
+mod parent_locales;
 mod plurals;
 
+pub use parent_locales::ParentLocalesProvider;
 pub use plurals::PluralsProvider;
 
 use crate::support::LazyCldrProvider;
@@ -9,6 +11,7 @@ use icu_data_provider::prelude::*;
 
 pub struct CldrJsonDataProvider<'a, 'd> {
     pub cldr_paths: &'a CldrPaths,
+    parent_locales: LazyCldrProvider<ParentLocalesProvider<'d>>,
     plurals: LazyCldrProvider<PluralsProvider<'d>>,
 }
 
@@ -16,6 +19,7 @@ impl<'a, 'd> CldrJsonDataProvider<'a, 'd> {
     pub fn new(cldr_paths: &'a CldrPaths) -> Self {
         CldrJsonDataProvider {
             cldr_paths,
+            parent_locales: Default::default(),
             plurals: Default::default(),
         }
     }
@@ -23,9 +27,13 @@ impl<'a, 'd> CldrJsonDataProvider<'a, 'd> {
 
 impl<'a, 'd> DataProvider<'d> for CldrJsonDataProvider<'a, 'd> {
     fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, DataError> {
-        self.plurals
-            .try_load(req, &self.cldr_paths)?
-            .ok_or(DataError::UnsupportedDataKey(req.data_key))
+        if let Some(resp) = self.parent_locales.try_load(req, self.cldr_paths)? {
+            return Ok(resp);
+        }
+        if let Some(resp) = self.plurals.try_load(req, &self.cldr_paths)? {
+            return Ok(resp);
+        }
+        Err(DataError::UnsupportedDataKey(req.data_key))
     }
 }
 
@@ -34,6 +42,9 @@ impl<'a, 'd> DataEntryCollection for CldrJsonDataProvider<'a, 'd> {
         &self,
         data_key: &DataKey,
     ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {
+        if let Some(resp) = self.parent_locales.try_iter(data_key, self.cldr_paths)? {
+            return Ok(resp);
+        }
         if let Some(resp) = self.plurals.try_iter(data_key, &self.cldr_paths)? {
             return Ok(resp);
         }
@@ -0,0 +1,161 @@
+use crate::error::Error;
+use crate::metadata::make_metadata;
+use crate::reader::open_reader;
+use crate::support::DataKeySupport;
+use crate::CldrPaths;
+use icu_data_provider::iter::DataEntryCollection;
+use icu_data_provider::prelude::*;
+use icu_data_provider::structs::fallback::*;
+use icu_locale::LanguageIdentifier;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// A data provider reading from the CLDR JSON parent locales file.
+#[derive(PartialEq, Debug)]
+pub struct ParentLocalesProvider<'d> {
+    parent_locales: cldr_json::ParentLocales,
+    metadata: DataResponseMetadata,
+    _phantom: PhantomData<&'d ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&CldrPaths> for ParentLocalesProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &CldrPaths) -> Result<Self, Self::Error> {
+        let path = cldr_paths
+            .cldr_core
+            .clone()?
+            .join("supplemental")
+            .join("parentLocales.json");
+        let data: cldr_json::Resource = serde_json::from_reader(open_reader(path.clone())?)?;
+        Ok(ParentLocalesProvider {
+            parent_locales: data.supplemental.parent_locales,
+            metadata: make_metadata(Some(&path), data.supplemental.version.as_ref()),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'d> TryFrom<&'d str> for ParentLocalesProvider<'d> {
+    type Error = serde_json::error::Error;
+    /// Attempt to parse a JSON string.
+    fn try_from(s: &'d str) -> Result<Self, Self::Error> {
+        let data: cldr_json::Resource = serde_json::from_str(s)?;
+        Ok(ParentLocalesProvider {
+            parent_locales: data.supplemental.parent_locales,
+            metadata: make_metadata(None, data.supplemental.version.as_ref()),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'d> DataKeySupport for ParentLocalesProvider<'d> {
+    fn supports_key(data_key: &DataKey) -> Result<(), DataError> {
+        if data_key.category != DataCategory::Fallback {
+            return Err((&data_key.category).into());
+        }
+        match *data_key {
+            key::PARENTS_V1 => Ok(()),
+            _ => Err(data_key.into()),
+        }
+    }
+}
+
+impl<'d> DataProvider<'d> for ParentLocalesProvider<'d> {
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, DataError> {
+        ParentLocalesProvider::supports_key(&req.data_key)?;
+        // The parent locales table is not locale-specific; it is only available in root.
+        if req.data_entry.variant.is_some()
+            || req.data_entry.langid != LanguageIdentifier::default()
+        {
+            return Err(req.clone().into());
+        }
+        let mut response = DataResponseBuilder {
+            data_langid: req.data_entry.langid.clone(),
+        }
+        .with_owned_payload(ParentLocalesV1::from(&self.parent_locales));
+        response.metadata = self.metadata.clone();
+        Ok(response)
+    }
+}
+
+impl<'d> DataEntryCollection for ParentLocalesProvider<'d> {
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {
+        ParentLocalesProvider::supports_key(data_key)?;
+        let list: Vec<DataEntry> = vec![DataEntry {
+            variant: None,
+            langid: LanguageIdentifier::default(),
+        }];
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+impl From<&cldr_json::ParentLocales> for ParentLocalesV1 {
+    fn from(other: &cldr_json::ParentLocales) -> ParentLocalesV1 {
+        let mut parents: Vec<(LanguageIdentifier, LanguageIdentifier)> = other
+            .parent_locale
+            .iter()
+            .map(|(child, parent)| (child.langid.clone(), parent.langid.clone()))
+            .collect();
+        parents.sort();
+        ParentLocalesV1 { parents }
+    }
+}
+
+/// Serde structs for the CLDR JSON parent locales file.
+pub(self) mod cldr_json {
+    use crate::cldr_langid::CldrLangID;
+    use crate::metadata::CldrVersion;
+    use serde::Deserialize;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct ParentLocales {
+        #[serde(rename = "parentLocale", with = "tuple_vec_map")]
+        pub(crate) parent_locale: Vec<(CldrLangID, CldrLangID)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Supplemental {
+        pub version: Option<CldrVersion>,
+        #[serde(rename = "parentLocales")]
+        pub parent_locales: ParentLocales,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub supplemental: Supplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use std::borrow::Cow;
+
+    let json_str = std::fs::read_to_string("tests/testdata/parentLocales.json").unwrap();
+    let provider = ParentLocalesProvider::try_from(json_str.as_str()).unwrap();
+
+    let response = provider
+        .load(&DataRequest {
+            data_key: key::PARENTS_V1,
+            data_entry: DataEntry {
+                variant: None,
+                langid: LanguageIdentifier::default(),
+            },
+        })
+        .unwrap();
+    assert_eq!(response.metadata.data_version.as_deref(), Some("37"));
+    let parent_locales: Cow<ParentLocalesV1> = response.take_payload().unwrap();
+
+    let get_parent = |s: &str| {
+        parent_locales
+            .get_parent(&s.parse().unwrap())
+            .map(|langid| langid.to_string())
+    };
+    assert_eq!(Some("es-419".to_string()), get_parent("es-MX"));
+    assert_eq!(Some("und".to_string()), get_parent("zh-Hant"));
+    assert_eq!(Some("zh-Hant-HK".to_string()), get_parent("zh-Hant-MO"));
+    assert_eq!(None, get_parent("es-ES"));
+    assert_eq!(None, get_parent("zh"));
+}
@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::metadata::make_metadata;
 use crate::reader::open_reader;
 use crate::support::DataKeySupport;
 use crate::CldrPaths;
@@ -15,33 +16,39 @@ use std::marker::PhantomData;
 pub struct PluralsProvider<'d> {
     cardinal_rules: Option<cldr_json::Rules>,
     ordinal_rules: Option<cldr_json::Rules>,
+    cardinal_metadata: DataResponseMetadata,
+    ordinal_metadata: DataResponseMetadata,
     _phantom: PhantomData<&'d ()>, // placeholder for when we need the lifetime param
 }
 
 impl TryFrom<&CldrPaths> for PluralsProvider<'_> {
     type Error = Error;
     fn try_from(cldr_paths: &CldrPaths) -> Result<Self, Self::Error> {
-        let cardinal_rules = {
+        let (cardinal_rules, cardinal_metadata) = {
             let path = cldr_paths
                 .cldr_core
                 .clone()?
                 .join("supplemental")
                 .join("plurals.json");
-            let data: cldr_json::Resource = serde_json::from_reader(open_reader(path)?)?;
-            data.supplemental.plurals_type_cardinal
+            let data: cldr_json::Resource = serde_json::from_reader(open_reader(path.clone())?)?;
+            let metadata = make_metadata(Some(&path), data.supplemental.version.as_ref());
+            (data.supplemental.plurals_type_cardinal, metadata)
         };
-        let ordinal_rules = {
+        let (ordinal_rules, ordinal_metadata) = {
             let path = cldr_paths
                 .cldr_core
                 .clone()?
                 .join("supplemental")
                 .join("ordinals.json");
-            let data: cldr_json::Resource = serde_json::from_reader(open_reader(path)?)?;
-            data.supplemental.plurals_type_ordinal
+            let data: cldr_json::Resource = serde_json::from_reader(open_reader(path.clone())?)?;
+            let metadata = make_metadata(Some(&path), data.supplemental.version.as_ref());
+            (data.supplemental.plurals_type_ordinal, metadata)
         };
         Ok(PluralsProvider {
             cardinal_rules,
             ordinal_rules,
+            cardinal_metadata,
+            ordinal_metadata,
             _phantom: PhantomData,
         })
     }
@@ -52,9 +59,12 @@ impl<'d> TryFrom<&'d str> for PluralsProvider<'d> {
     /// Attempt to parse a JSON string.
     fn try_from(s: &'d str) -> Result<Self, Self::Error> {
         let data: cldr_json::Resource = serde_json::from_str(s)?;
+        let metadata = make_metadata(None, data.supplemental.version.as_ref());
         Ok(PluralsProvider {
             cardinal_rules: data.supplemental.plurals_type_cardinal,
             ordinal_rules: data.supplemental.plurals_type_ordinal,
+            cardinal_metadata: metadata.clone(),
+            ordinal_metadata: metadata,
             _phantom: PhantomData,
         })
     }
@@ -73,32 +83,39 @@ impl<'d> DataKeySupport for PluralsProvider<'d> {
 }
 
 impl<'d> PluralsProvider<'d> {
-    fn get_rules_for(&self, data_key: &DataKey) -> Result<&cldr_json::Rules, DataError> {
+    fn get_rules_for(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<(&cldr_json::Rules, &DataResponseMetadata), DataError> {
         PluralsProvider::supports_key(data_key)?;
-        match data_key.sub_category.as_str() {
-            // TODO(#212): Match on TinyStr
-            "cardinal" => self.cardinal_rules.as_ref(),
-            "ordinal" => self.ordinal_rules.as_ref(),
+        let (rules, metadata) = match *data_key {
+            key::CARDINAL_V1 => (&self.cardinal_rules, &self.cardinal_metadata),
+            key::ORDINAL_V1 => (&self.ordinal_rules, &self.ordinal_metadata),
             _ => return Err(data_key.into()),
+        };
+        match rules {
+            Some(rules) => Ok((rules, metadata)),
+            None => Err(data_key.into()),
         }
-        .ok_or_else(|| data_key.into())
     }
 }
 
 impl<'d> DataProvider<'d> for PluralsProvider<'d> {
     fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, DataError> {
-        let cldr_rules = self.get_rules_for(&req.data_key)?;
-        // TODO: Implement language fallback?
+        let (cldr_rules, metadata) = self.get_rules_for(&req.data_key)?;
+        // Language fallback is performed by LocaleFallbackProvider.
         // TODO: Avoid the clone
         let cldr_langid = req.data_entry.langid.clone().into();
         let (_, r) = match cldr_rules.0.binary_search_by_key(&&cldr_langid, |(l, _)| l) {
             Ok(idx) => &cldr_rules.0[idx],
             Err(_) => return Err(req.clone().into()),
         };
-        Ok(DataResponseBuilder {
+        let mut response = DataResponseBuilder {
             data_langid: req.data_entry.langid.clone(),
         }
-        .with_owned_payload(PluralRuleStringsV1::from(r)))
+        .with_owned_payload(PluralRuleStringsV1::from(r));
+        response.metadata = metadata.clone();
+        Ok(response)
     }
 }
 
@@ -107,7 +124,7 @@ impl<'d> DataEntryCollection for PluralsProvider<'d> {
         &self,
         data_key: &DataKey,
     ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {
-        let cldr_rules = self.get_rules_for(data_key)?;
+        let (cldr_rules, _) = self.get_rules_for(data_key)?;
         let list: Vec<DataEntry> = cldr_rules
             .0
             .iter()
@@ -121,8 +138,8 @@ impl<'d> DataEntryCollection for PluralsProvider<'d> {
     }
 }
 
-impl From<&cldr_json::LocalePluralRules> for PluralRuleStringsV1 {
-    fn from(other: &cldr_json::LocalePluralRules) -> PluralRuleStringsV1 {
+impl From<&cldr_json::LocalePluralRules> for PluralRuleStringsV1<'static> {
+    fn from(other: &cldr_json::LocalePluralRules) -> PluralRuleStringsV1<'static> {
         #[allow(clippy::ptr_arg)]
         fn convert(s: &Cow<'static, str>) -> Cow<'static, str> {
             let mut ast = parse(s.as_bytes()).expect("Rule parsing failed.");
@@ -144,6 +161,7 @@ impl From<&cldr_json::LocalePluralRules> for PluralRuleStringsV1 {
 /// Serde structs for the CLDR JSON plurals files.
 pub(self) mod cldr_json {
     use crate::cldr_langid::CldrLangID;
+    use crate::metadata::CldrVersion;
     use serde::Deserialize;
     use std::borrow::Cow;
 
@@ -171,6 +189,7 @@ pub(self) mod cldr_json {
 
     #[derive(PartialEq, Debug, Deserialize)]
     pub struct Supplemental {
+        pub version: Option<CldrVersion>,
         #[serde(rename = "plurals-type-cardinal")]
         pub plurals_type_cardinal: Option<Rules>,
         #[serde(rename = "plurals-type-ordinal")]
@@ -191,17 +210,17 @@ fn test_basic() {
     let provider = PluralsProvider::try_from(json_str.as_str()).unwrap();
 
     // Spot-check locale 'cs' since it has some interesting entries
-    let cs_rules: Cow<PluralRuleStringsV1> = provider
+    let response = provider
         .load(&DataRequest {
-            data_key: icu_data_key!(plurals: cardinal@1),
+            data_key: key::CARDINAL_V1,
             data_entry: DataEntry {
                 variant: None,
                 langid: "cs".parse().unwrap(),
             },
         })
-        .unwrap()
-        .take_payload()
         .unwrap();
+    assert_eq!(response.metadata.data_version.as_deref(), Some("37"));
+    let cs_rules: Cow<PluralRuleStringsV1> = response.take_payload().unwrap();
 
     assert_eq!(None, cs_rules.zero);
     assert_eq!(
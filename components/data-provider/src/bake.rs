@@ -0,0 +1,85 @@
+//! Conversion of data structs to Rust source code, also known as "baking" data.
+//!
+//! Baked data is compiled into the binary, so it can be loaded without any I/O or
+//! deserialization at runtime. The data structs in the [`structs`](crate::structs) module
+//! implement [`Bake`], and [`structs::get_baker`](crate::structs::get_baker) gets a function that
+//! bakes serialized data for a particular data key, such as the data passed to a
+//! [`DataExporter`](crate::iter::DataExporter).
+//!
+//! # Example
+//!
+//! ```
+//! use icu_data_provider::bake::Bake;
+//! use icu_data_provider::structs::plurals::PluralRuleStringsV1;
+//! use std::borrow::Cow;
+//!
+//! let rules = PluralRuleStringsV1 {
+//!     zero: None,
+//!     one: Some(Cow::Borrowed("i = 1")),
+//!     two: None,
+//!     few: None,
+//!     many: None,
+//! };
+//! assert!(PluralRuleStringsV1::IS_CONST);
+//! assert!(rules.bake().contains(r#"one: Some(::std::borrow::Cow::Borrowed("i = 1"))"#));
+//! ```
+
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+
+/// A data struct that can be converted to Rust source code.
+pub trait Bake {
+    /// The absolute path of the type, usable from any crate depending on `icu_data_provider`.
+    const TYPE_PATH: &'static str;
+
+    /// Whether [`bake`](Bake::bake) returns a constant expression, which can initialize a
+    /// `static` item. Otherwise, the expression is evaluated once, when the data is first used.
+    const IS_CONST: bool;
+
+    /// Returns a Rust expression that evaluates to a value equal to `self`.
+    fn bake(&self) -> String;
+}
+
+/// A data struct converted to Rust source code.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BakedPayload {
+    /// See [`Bake::TYPE_PATH`].
+    pub type_path: &'static str,
+    /// See [`Bake::IS_CONST`].
+    pub is_const: bool,
+    /// The Rust expression returned by [`Bake::bake`].
+    pub expression: String,
+}
+
+/// A function that deserializes the payload for a particular data key and bakes it.
+///
+/// Obtain the function for a data key with [`structs::get_baker`](crate::structs::get_baker).
+pub type BakeFn = for<'de> fn(
+    &mut dyn erased_serde::Deserializer<'de>,
+) -> Result<BakedPayload, erased_serde::Error>;
+
+/// Deserializes a data struct T and bakes it. Used as the [`BakeFn`] for each data key in the
+/// `structs` module.
+pub(crate) fn bake_deserialized<T>(
+    deserializer: &mut dyn erased_serde::Deserializer,
+) -> Result<BakedPayload, erased_serde::Error>
+where
+    T: DeserializeOwned + Bake,
+{
+    let obj: T = erased_serde::deserialize(deserializer)?;
+    Ok(BakedPayload {
+        type_path: T::TYPE_PATH,
+        is_const: T::IS_CONST,
+        expression: obj.bake(),
+    })
+}
+
+/// Bakes an optional string as a constant `Option<Cow<'static, str>>` expression.
+pub(crate) fn bake_option_cow_str(value: &Option<Cow<'static, str>>) -> String {
+    match value {
+        // The Debug form of a str is a valid Rust string literal.
+        Some(s) => format!("Some(::std::borrow::Cow::Borrowed({:?}))", s),
+        None => "None".to_string(),
+    }
+}
@@ -0,0 +1,403 @@
+use crate::error::Error;
+use crate::iter::DataEntryCollection;
+use crate::prelude::*;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+/// Strategy for choosing which response to evict when a CachingDataProvider is full.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum EvictionPolicy {
+    /// Evict the response that was least recently loaded from the cache.
+    LeastRecentlyUsed,
+    /// Evict the response that was least recently inserted into the cache, regardless of how
+    /// often it has been loaded since.
+    FirstInFirstOut,
+}
+
+/// Options bag for initializing a CachingDataProvider.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CacheOptions {
+    /// Maximum number of responses to keep in the cache. A capacity of 0 disables caching.
+    pub capacity: usize,
+    /// Strategy for choosing which response to evict when the cache is full.
+    pub eviction: EvictionPolicy,
+}
+
+impl Default for CacheOptions {
+    fn default() -> Self {
+        Self {
+            capacity: 64,
+            eviction: EvictionPolicy::LeastRecentlyUsed,
+        }
+    }
+}
+
+/// Counters describing the effectiveness of a CachingDataProvider.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct CacheStats {
+    /// Number of requests answered from the cache.
+    pub hits: u64,
+    /// Number of requests forwarded to the inner data provider.
+    pub misses: u64,
+    /// Number of responses removed from the cache to make room for new ones.
+    pub evictions: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry<'d> {
+    response: DataResponse<'d>,
+    tick: u64,
+}
+
+#[derive(Debug, Default)]
+struct CacheState<'d> {
+    entries: HashMap<DataRequest, CacheEntry<'d>>,
+    /// The requests in `entries`, ordered by the tick at which they were last touched.
+    order: BTreeMap<u64, DataRequest>,
+    next_tick: u64,
+    stats: CacheStats,
+}
+
+impl<'d> CacheState<'d> {
+    fn next_tick(&mut self) -> u64 {
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        tick
+    }
+
+    fn get(&mut self, req: &DataRequest, eviction: EvictionPolicy) -> Option<DataResponse<'d>> {
+        let tick = self.next_tick();
+        let entry = self.entries.get_mut(req)?;
+        if eviction == EvictionPolicy::LeastRecentlyUsed {
+            let req = self
+                .order
+                .remove(&entry.tick)
+                .expect("Every cache entry has a position in the eviction order");
+            entry.tick = tick;
+            self.order.insert(tick, req);
+        }
+        Some(entry.response.clone())
+    }
+
+    fn insert(&mut self, req: &DataRequest, response: DataResponse<'d>, capacity: usize) {
+        if capacity == 0 || self.entries.contains_key(req) {
+            return;
+        }
+        while self.entries.len() >= capacity {
+            let oldest_tick = *self
+                .order
+                .keys()
+                .next()
+                .expect("The eviction order is not empty if the cache is not empty");
+            if let Some(evicted) = self.order.remove(&oldest_tick) {
+                self.entries.remove(&evicted);
+            }
+            self.stats.evictions += 1;
+        }
+        let tick = self.next_tick();
+        self.order.insert(tick, req.clone());
+        self.entries
+            .insert(req.clone(), CacheEntry { response, tick });
+    }
+}
+
+/// A data provider that memoizes the responses of another data provider in memory.
+///
+/// Successful responses are cached by DataRequest, up to a fixed capacity; errors are not cached.
+/// Owned payloads are moved behind an `Arc` when they are cached, so a cache hit does not clone
+/// the data.
+///
+/// CachingDataProvider can be shared between threads if the inner data provider can.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::{CacheOptions, CachingDataProvider};
+///
+/// struct MyProvider;
+///
+/// impl DataProvider<'static> for MyProvider {
+///     fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+///         Ok(DataResponseBuilder {
+///             data_langid: req.data_entry.langid.clone(),
+///         }
+///         .with_owned_payload("Hello".to_string()))
+///     }
+/// }
+///
+/// let provider = CachingDataProvider::new(MyProvider, CacheOptions::default());
+/// let req = DataRequest {
+///     data_key: icu_data_key!(plurals: cardinal@1),
+///     data_entry: DataEntry {
+///         variant: None,
+///         langid: "en".parse().unwrap(),
+///     },
+/// };
+/// provider.load(&req).unwrap();
+/// provider.load(&req).unwrap();
+///
+/// let stats = provider.stats();
+/// assert_eq!(stats.misses, 1);
+/// assert_eq!(stats.hits, 1);
+/// ```
+#[derive(Debug)]
+pub struct CachingDataProvider<'d, P> {
+    inner: P,
+    options: CacheOptions,
+    state: Mutex<CacheState<'d>>,
+}
+
+impl<'d, P> CachingDataProvider<'d, P> {
+    /// Wrap a data provider, caching its responses.
+    pub fn new(inner: P, options: CacheOptions) -> Self {
+        Self {
+            inner,
+            options,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Get the options this CachingDataProvider was created with.
+    pub fn options(&self) -> &CacheOptions {
+        &self.options
+    }
+
+    /// Get a snapshot of the cache statistics.
+    pub fn stats(&self) -> CacheStats {
+        self.lock_state().stats
+    }
+
+    /// Get the number of responses currently in the cache.
+    pub fn len(&self) -> usize {
+        self.lock_state().entries.len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock_state().entries.is_empty()
+    }
+
+    /// Remove all responses from the cache. The statistics are not reset.
+    pub fn clear(&self) {
+        let mut state = self.lock_state();
+        state.entries.clear();
+        state.order.clear();
+    }
+
+    /// Get a reference to the wrapped data provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Unwrap the inner data provider. Consumes the CachingDataProvider.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, CacheState<'d>> {
+        // The cache state is consistent between operations, so it is safe to keep using it even
+        // if another thread panicked while holding the lock.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<'d, P> DataProvider<'d> for CachingDataProvider<'d, P>
+where
+    P: DataProvider<'d>,
+{
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, Error> {
+        {
+            let mut state = self.lock_state();
+            if let Some(response) = state.get(req, self.options.eviction) {
+                state.stats.hits += 1;
+                return Ok(response);
+            }
+            state.stats.misses += 1;
+        }
+        // Do not hold the lock while loading, so that other threads are not blocked on I/O.
+        let response = self.inner.load(req)?.into_shared();
+        self.lock_state()
+            .insert(req, response.clone(), self.options.capacity);
+        Ok(response)
+    }
+}
+
+impl<'d, P> DataEntryCollection for CachingDataProvider<'d, P>
+where
+    P: DataEntryCollection,
+{
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, Error> {
+        self.inner.iter_for_key(data_key)
+    }
+}
+
+#[cfg(test)]
+struct CountingProvider {
+    loads: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(test)]
+impl DataProvider<'static> for CountingProvider {
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, Error> {
+        self.loads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if req.data_entry.langid == "xx" {
+            return Err(Error::UnavailableEntry(req.clone()));
+        }
+        Ok(DataResponseBuilder {
+            data_langid: req.data_entry.langid.clone(),
+        }
+        .with_owned_payload(req.data_entry.to_string()))
+    }
+}
+
+#[cfg(test)]
+fn make_caching_provider(
+    capacity: usize,
+    eviction: EvictionPolicy,
+) -> CachingDataProvider<'static, CountingProvider> {
+    CachingDataProvider::new(
+        CountingProvider {
+            loads: Default::default(),
+        },
+        CacheOptions { capacity, eviction },
+    )
+}
+
+#[cfg(test)]
+fn load_langid(provider: &CachingDataProvider<'static, CountingProvider>, langid: &str) -> String {
+    let response = provider
+        .load(&DataRequest {
+            data_key: icu_data_key!(plurals: cardinal@1),
+            data_entry: DataEntry {
+                variant: None,
+                langid: langid.parse().unwrap(),
+            },
+        })
+        .unwrap();
+    response.borrow_payload::<String>().unwrap().clone()
+}
+
+#[cfg(test)]
+fn inner_loads(provider: &CachingDataProvider<'static, CountingProvider>) -> usize {
+    provider
+        .inner()
+        .loads
+        .load(std::sync::atomic::Ordering::SeqCst)
+}
+
+#[test]
+fn test_cache_hits() {
+    let provider = make_caching_provider(4, EvictionPolicy::LeastRecentlyUsed);
+    assert_eq!(load_langid(&provider, "en"), "en");
+    assert_eq!(load_langid(&provider, "en"), "en");
+    assert_eq!(load_langid(&provider, "de"), "de");
+    assert_eq!(load_langid(&provider, "en"), "en");
+    assert_eq!(inner_loads(&provider), 2);
+    assert_eq!(
+        provider.stats(),
+        CacheStats {
+            hits: 2,
+            misses: 2,
+            evictions: 0
+        }
+    );
+    assert_eq!(provider.len(), 2);
+
+    provider.clear();
+    assert!(provider.is_empty());
+    load_langid(&provider, "en");
+    assert_eq!(inner_loads(&provider), 3);
+}
+
+#[test]
+fn test_errors_not_cached() {
+    let provider = make_caching_provider(4, EvictionPolicy::LeastRecentlyUsed);
+    let req = DataRequest {
+        data_key: icu_data_key!(plurals: cardinal@1),
+        data_entry: DataEntry {
+            variant: None,
+            langid: "xx".parse().unwrap(),
+        },
+    };
+    assert!(matches!(
+        provider.load(&req),
+        Err(Error::UnavailableEntry(_))
+    ));
+    assert!(matches!(
+        provider.load(&req),
+        Err(Error::UnavailableEntry(_))
+    ));
+    assert_eq!(inner_loads(&provider), 2);
+    assert!(provider.is_empty());
+}
+
+#[test]
+fn test_lru_eviction() {
+    let provider = make_caching_provider(2, EvictionPolicy::LeastRecentlyUsed);
+    load_langid(&provider, "en");
+    load_langid(&provider, "de");
+    // Touch "en" so that "de" becomes the least recently used.
+    load_langid(&provider, "en");
+    load_langid(&provider, "fr");
+    assert_eq!(provider.stats().evictions, 1);
+    assert_eq!(inner_loads(&provider), 3);
+    load_langid(&provider, "en");
+    assert_eq!(inner_loads(&provider), 3);
+    load_langid(&provider, "de");
+    assert_eq!(inner_loads(&provider), 4);
+}
+
+#[test]
+fn test_fifo_eviction() {
+    let provider = make_caching_provider(2, EvictionPolicy::FirstInFirstOut);
+    load_langid(&provider, "en");
+    load_langid(&provider, "de");
+    load_langid(&provider, "en");
+    load_langid(&provider, "fr");
+    assert_eq!(provider.stats().evictions, 1);
+    // "en" was inserted first, so it was evicted even though it was used recently.
+    load_langid(&provider, "de");
+    assert_eq!(inner_loads(&provider), 3);
+    load_langid(&provider, "en");
+    assert_eq!(inner_loads(&provider), 4);
+}
+
+#[test]
+fn test_zero_capacity() {
+    let provider = make_caching_provider(0, EvictionPolicy::LeastRecentlyUsed);
+    load_langid(&provider, "en");
+    load_langid(&provider, "en");
+    assert_eq!(inner_loads(&provider), 2);
+    assert!(provider.is_empty());
+}
+
+#[test]
+fn test_shared_between_threads() {
+    let provider = std::sync::Arc::new(make_caching_provider(4, EvictionPolicy::LeastRecentlyUsed));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let provider = provider.clone();
+            std::thread::spawn(move || {
+                for _ in 0..10 {
+                    assert_eq!(load_langid(&provider, "en"), "en");
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let stats = provider.stats();
+    assert_eq!(stats.hits + stats.misses, 40);
+    assert_eq!(stats.misses as usize, inner_loads(&provider));
+}
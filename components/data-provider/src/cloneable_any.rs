@@ -1,10 +1,13 @@
 use downcast_rs::impl_downcast;
-use downcast_rs::Downcast;
+use downcast_rs::DowncastSync;
 use std::fmt::Debug;
 
 // Please do not to make this trait public, because it is easy to use incorrectly. It is fine as
 // an internal auto-implemented trait.
-pub(super) trait CloneableAny: Debug + Downcast + erased_serde::Serialize {
+//
+// The Send + Sync supertraits (via DowncastSync) allow payloads to be moved and shared across
+// threads.
+pub(super) trait CloneableAny: Debug + DowncastSync + erased_serde::Serialize {
     fn clone_into_box(&self) -> Box<dyn CloneableAny>;
 
     fn as_serialize(&self) -> &dyn erased_serde::Serialize;
@@ -18,10 +21,10 @@ impl ToOwned for dyn CloneableAny {
     }
 }
 
-// Implement CloneableAny for all 'static types implementing Clone.
+// Implement CloneableAny for all 'static, thread-safe types implementing Clone.
 impl<T> CloneableAny for T
 where
-    T: 'static + Clone + Debug + erased_serde::Serialize,
+    T: 'static + Clone + Debug + erased_serde::Serialize + Send + Sync,
 {
     fn clone_into_box(&self) -> Box<dyn CloneableAny> {
         Box::new(self.clone())
@@ -32,5 +35,5 @@ where
     }
 }
 
-// Adds the Downcast methods to all 'static types implementing CloneableAny.
-impl_downcast!(CloneableAny);
+// Adds the Downcast methods, including those for Arc, to all types implementing CloneableAny.
+impl_downcast!(sync CloneableAny);
@@ -0,0 +1,487 @@
+use crate::error::Error;
+use crate::iter::DataEntryCollection;
+use crate::prelude::*;
+use std::collections::HashSet;
+
+/// Returns whether the error means that a data provider does not support a DataKey at all, as
+/// opposed to failing to load it.
+fn is_unsupported(err: &Error) -> bool {
+    matches!(
+        err,
+        Error::UnsupportedCategory(_) | Error::UnsupportedDataKey(_)
+    )
+}
+
+/// Returns whether the error means that a data provider does not have the requested entry, so
+/// that the next data provider in a chain should be tried.
+fn is_missing(err: &Error) -> bool {
+    is_unsupported(err) || matches!(err, Error::UnavailableEntry(_))
+}
+
+/// A data provider that tries two data providers in order, returning the first response.
+///
+/// The second provider is queried if the first provider does not support the requested category
+/// or data key, following the semantics of `load_graceful`, or if it does not have the requested
+/// entry (`UnavailableEntry`). This matches [`DataEntryCollection`], which lists the entries of
+/// both providers. Other errors from the first provider are returned without querying the second
+/// provider.
+///
+/// To chain more than two providers, nest ChainProviders.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::ChainProvider;
+///
+/// /// A provider that only supports private-use data.
+/// struct PrivateUseProvider;
+///
+/// impl DataProvider<'static> for PrivateUseProvider {
+///     fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+///         match req.data_key.category {
+///             DataCategory::PrivateUse(_) => Ok(DataResponseBuilder {
+///                 data_langid: req.data_entry.langid.clone(),
+///             }
+///             .with_owned_payload("Private".to_string())),
+///             category => Err(DataError::UnsupportedCategory(category)),
+///         }
+///     }
+/// }
+///
+/// /// A provider that supports everything.
+/// struct FallbackProvider;
+///
+/// impl DataProvider<'static> for FallbackProvider {
+///     fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+///         Ok(DataResponseBuilder {
+///             data_langid: req.data_entry.langid.clone(),
+///         }
+///         .with_owned_payload("Fallback".to_string()))
+///     }
+/// }
+///
+/// let provider = ChainProvider::new(PrivateUseProvider, FallbackProvider);
+/// let response = provider
+///     .load(&DataRequest {
+///         data_key: icu_data_key!(plurals: cardinal@1),
+///         data_entry: DataEntry {
+///             variant: None,
+///             langid: "en".parse().unwrap(),
+///         },
+///     })
+///     .unwrap();
+/// assert_eq!(response.borrow_payload::<String>().unwrap(), "Fallback");
+/// ```
+#[derive(Debug)]
+pub struct ChainProvider<P0, P1> {
+    first: P0,
+    second: P1,
+}
+
+impl<P0, P1> ChainProvider<P0, P1> {
+    /// Chain two data providers. The first provider takes precedence.
+    pub fn new(first: P0, second: P1) -> Self {
+        Self { first, second }
+    }
+
+    /// Unwrap the inner data providers. Consumes the ChainProvider.
+    pub fn into_inner(self) -> (P0, P1) {
+        (self.first, self.second)
+    }
+}
+
+impl<'d, P0, P1> DataProvider<'d> for ChainProvider<P0, P1>
+where
+    P0: DataProvider<'d>,
+    P1: DataProvider<'d>,
+{
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, Error> {
+        match self.first.load(req) {
+            Err(err) if is_missing(&err) => match self.second.load(req) {
+                // Report that the first provider has no such entry, rather than that the second
+                // provider does not support the data key.
+                Err(second_err) if is_unsupported(&second_err) && !is_unsupported(&err) => Err(err),
+                result => result,
+            },
+            result => result,
+        }
+    }
+}
+
+/// Merges the entries of both data providers, in order and without duplicates. The data key must
+/// be supported by at least one of the providers.
+impl<P0, P1> DataEntryCollection for ChainProvider<P0, P1>
+where
+    P0: DataEntryCollection,
+    P1: DataEntryCollection,
+{
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, Error> {
+        let first = match self.first.iter_for_key(data_key) {
+            Ok(iter) => iter,
+            Err(err) if is_unsupported(&err) => return self.second.iter_for_key(data_key),
+            Err(err) => return Err(err),
+        };
+        let second = match self.second.iter_for_key(data_key) {
+            Ok(iter) => iter,
+            Err(err) if is_unsupported(&err) => return Ok(first),
+            Err(err) => return Err(err),
+        };
+        let mut seen = HashSet::new();
+        let list: Vec<DataEntry> = first
+            .chain(second)
+            .filter(|entry| seen.insert(entry.clone()))
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// The rule used by a ForkByKeyProvider to choose a data provider for a DataKey.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ForkRule {
+    /// Match data keys in any of the given categories.
+    Categories(Vec<DataCategory>),
+    /// Match any of the given data keys exactly.
+    Keys(Vec<DataKey>),
+}
+
+impl ForkRule {
+    /// Returns whether the data key matches this rule.
+    pub fn matches(&self, data_key: &DataKey) -> bool {
+        match self {
+            ForkRule::Categories(categories) => categories.contains(&data_key.category),
+            ForkRule::Keys(keys) => keys.contains(data_key),
+        }
+    }
+}
+
+/// A data provider that routes each request to one of two data providers based on its DataKey.
+///
+/// Requests whose data key matches the [`ForkRule`] are sent to the first provider; all other
+/// requests are sent to the second provider. Unlike [`ChainProvider`], only one provider is
+/// queried for each request.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::{ForkByKeyProvider, ForkRule};
+///
+/// struct NamedProvider(&'static str);
+///
+/// impl DataProvider<'static> for NamedProvider {
+///     fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+///         Ok(DataResponseBuilder {
+///             data_langid: req.data_entry.langid.clone(),
+///         }
+///         .with_owned_payload(self.0.to_string()))
+///     }
+/// }
+///
+/// let private_use = DataCategory::PrivateUse("acme".parse().unwrap());
+/// let provider = ForkByKeyProvider::new(
+///     NamedProvider("acme"),
+///     NamedProvider("cldr"),
+///     ForkRule::Categories(vec![private_use]),
+/// );
+///
+/// let load = |data_key| {
+///     let response = provider
+///         .load(&DataRequest {
+///             data_key,
+///             data_entry: DataEntry {
+///                 variant: None,
+///                 langid: "en".parse().unwrap(),
+///             },
+///         })
+///         .unwrap();
+///     response.borrow_payload::<String>().unwrap().clone()
+/// };
+/// assert_eq!(load(icu_data_key!(x-acme: greeting@1)), "acme");
+/// assert_eq!(load(icu_data_key!(plurals: cardinal@1)), "cldr");
+/// ```
+#[derive(Debug)]
+pub struct ForkByKeyProvider<P0, P1> {
+    first: P0,
+    second: P1,
+    rule: ForkRule,
+}
+
+impl<P0, P1> ForkByKeyProvider<P0, P1> {
+    /// Create a provider sending requests that match `rule` to `first`, and all other requests to
+    /// `second`.
+    pub fn new(first: P0, second: P1, rule: ForkRule) -> Self {
+        Self {
+            first,
+            second,
+            rule,
+        }
+    }
+
+    /// Get the rule used to route requests.
+    pub fn rule(&self) -> &ForkRule {
+        &self.rule
+    }
+
+    /// Unwrap the inner data providers. Consumes the ForkByKeyProvider.
+    pub fn into_inner(self) -> (P0, P1) {
+        (self.first, self.second)
+    }
+}
+
+impl<'d, P0, P1> DataProvider<'d> for ForkByKeyProvider<P0, P1>
+where
+    P0: DataProvider<'d>,
+    P1: DataProvider<'d>,
+{
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, Error> {
+        if self.rule.matches(&req.data_key) {
+            self.first.load(req)
+        } else {
+            self.second.load(req)
+        }
+    }
+}
+
+/// Returns the entries of the data provider that the data key is routed to.
+impl<P0, P1> DataEntryCollection for ForkByKeyProvider<P0, P1>
+where
+    P0: DataEntryCollection,
+    P1: DataEntryCollection,
+{
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, Error> {
+        if self.rule.matches(data_key) {
+            self.first.iter_for_key(data_key)
+        } else {
+            self.second.iter_for_key(data_key)
+        }
+    }
+}
+
+#[cfg(test)]
+struct TestProvider {
+    name: &'static str,
+    category: DataCategory,
+    entries: Vec<&'static str>,
+}
+
+#[cfg(test)]
+impl DataProvider<'static> for TestProvider {
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, Error> {
+        if req.data_key.category != self.category {
+            return Err(Error::UnsupportedCategory(req.data_key.category));
+        }
+        if !self.entries.contains(&req.data_entry.to_string().as_str()) {
+            return Err(Error::UnavailableEntry(req.clone()));
+        }
+        Ok(DataResponseBuilder {
+            data_langid: req.data_entry.langid.clone(),
+        }
+        .with_owned_payload(self.name.to_string()))
+    }
+}
+
+#[cfg(test)]
+impl DataEntryCollection for TestProvider {
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, Error> {
+        if data_key.category != self.category {
+            return Err(Error::UnsupportedCategory(data_key.category));
+        }
+        let list: Vec<DataEntry> = self
+            .entries
+            .iter()
+            .map(|langid| DataEntry {
+                variant: None,
+                langid: langid.parse().unwrap(),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[cfg(test)]
+fn make_test_providers() -> (TestProvider, TestProvider) {
+    (
+        TestProvider {
+            name: "plurals",
+            category: DataCategory::Plurals,
+            entries: vec!["en", "sr"],
+        },
+        TestProvider {
+            name: "decimal",
+            category: DataCategory::Decimal,
+            entries: vec!["en", "de"],
+        },
+    )
+}
+
+#[cfg(test)]
+fn load_name(provider: &dyn DataProvider<'static>, data_key: DataKey, langid: &str) -> String {
+    let response = provider
+        .load(&DataRequest {
+            data_key,
+            data_entry: DataEntry {
+                variant: None,
+                langid: langid.parse().unwrap(),
+            },
+        })
+        .unwrap();
+    response.borrow_payload::<String>().unwrap().clone()
+}
+
+#[cfg(test)]
+fn collect_entries(provider: &dyn DataEntryCollection, data_key: &DataKey) -> Vec<String> {
+    provider
+        .iter_for_key(data_key)
+        .unwrap()
+        .map(|entry| entry.to_string())
+        .collect()
+}
+
+/// Records the entries and JSON payloads passed to it.
+#[cfg(test)]
+#[derive(Default)]
+struct TestExporter(Vec<(String, String)>);
+
+#[cfg(test)]
+impl crate::iter::DataExporter for TestExporter {
+    fn put(
+        &mut self,
+        req: &DataRequest,
+        obj: &dyn erased_serde::Serialize,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let json = serde_json::to_string(obj)?;
+        self.0.push((req.data_entry.to_string(), json));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_chain() {
+    let (plurals, decimal) = make_test_providers();
+    let provider = ChainProvider::new(plurals, decimal);
+    assert_eq!(
+        load_name(&provider, icu_data_key!(plurals: cardinal@1), "en"),
+        "plurals"
+    );
+    assert_eq!(
+        load_name(&provider, icu_data_key!(decimal: symbols@1), "en"),
+        "decimal"
+    );
+
+    // UnavailableEntry is returned if the second provider does not support the data key.
+    let result = provider.load(&DataRequest {
+        data_key: icu_data_key!(plurals: cardinal@1),
+        data_entry: DataEntry {
+            variant: None,
+            langid: "de".parse().unwrap(),
+        },
+    });
+    assert!(matches!(result, Err(Error::UnavailableEntry(_))));
+
+    // Unsupported by both providers.
+    let result = provider.iter_for_key(&icu_data_key!(fallback: parents@1));
+    assert!(matches!(result, Err(Error::UnsupportedCategory(_))));
+}
+
+#[test]
+fn test_chain_merges_entries() {
+    let provider = ChainProvider::new(
+        TestProvider {
+            name: "first",
+            category: DataCategory::Plurals,
+            entries: vec!["en", "sr"],
+        },
+        TestProvider {
+            name: "second",
+            category: DataCategory::Plurals,
+            entries: vec!["sr", "de"],
+        },
+    );
+    let data_key = icu_data_key!(plurals: cardinal@1);
+    assert_eq!(
+        collect_entries(&provider, &data_key),
+        vec!["en", "sr", "de"]
+    );
+    // Every entry can be loaded. The first provider takes precedence for entries they both have.
+    let names: Vec<String> = collect_entries(&provider, &data_key)
+        .iter()
+        .map(|langid| load_name(&provider, data_key, langid))
+        .collect();
+    assert_eq!(names, vec!["first", "first", "second"]);
+
+    use crate::iter::IterableDataProvider;
+    let mut exporter = TestExporter::default();
+    provider
+        .export_key(&data_key, &mut exporter)
+        .expect("Should export every entry");
+    assert_eq!(
+        exporter.0,
+        vec![
+            ("en".to_string(), "\"first\"".to_string()),
+            ("sr".to_string(), "\"first\"".to_string()),
+            ("de".to_string(), "\"second\"".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_fork_by_category() {
+    let (plurals, decimal) = make_test_providers();
+    let provider = ForkByKeyProvider::new(
+        decimal,
+        plurals,
+        ForkRule::Categories(vec![DataCategory::Decimal]),
+    );
+    assert_eq!(
+        load_name(&provider, icu_data_key!(decimal: symbols@1), "de"),
+        "decimal"
+    );
+    assert_eq!(
+        load_name(&provider, icu_data_key!(plurals: ordinal@1), "sr"),
+        "plurals"
+    );
+    assert_eq!(
+        collect_entries(&provider, &icu_data_key!(decimal: symbols@1)),
+        vec!["en", "de"]
+    );
+    assert_eq!(
+        collect_entries(&provider, &icu_data_key!(plurals: cardinal@1)),
+        vec!["en", "sr"]
+    );
+}
+
+#[test]
+fn test_fork_by_key() {
+    let provider = ForkByKeyProvider::new(
+        TestProvider {
+            name: "ordinal",
+            category: DataCategory::Plurals,
+            entries: vec!["en"],
+        },
+        TestProvider {
+            name: "cardinal",
+            category: DataCategory::Plurals,
+            entries: vec!["en"],
+        },
+        ForkRule::Keys(vec![icu_data_key!(plurals: ordinal@1)]),
+    );
+    assert_eq!(
+        load_name(&provider, icu_data_key!(plurals: ordinal@1), "en"),
+        "ordinal"
+    );
+    assert_eq!(
+        load_name(&provider, icu_data_key!(plurals: cardinal@1), "en"),
+        "cardinal"
+    );
+}
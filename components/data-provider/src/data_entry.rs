@@ -1,13 +1,15 @@
+use crate::error::ParseError;
 use icu_locale::LanguageIdentifier;
 use std::borrow::Borrow;
 use std::borrow::Cow;
 use std::fmt;
 use std::fmt::Write;
+use std::str::FromStr;
 
 /// A variant and language identifier, used for requesting data from a DataProvider.
 ///
 /// The fields in a DataEntry are not generally known until runtime.
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct DataEntry {
     // TODO: Consider making this a list of variants
     pub variant: Option<Cow<'static, str>>,
@@ -35,6 +37,30 @@ impl fmt::Display for DataEntry {
     }
 }
 
+impl FromStr for DataEntry {
+    type Err = ParseError;
+
+    /// Parses a string of the form `[variant/]langid`, the inverse of the Display implementation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.rsplitn(2, '/');
+        let langid = parts
+            .next()
+            .ok_or_else(|| ParseError::InvalidDataEntry(s.to_string()))?;
+        let variant = match parts.next() {
+            Some("") => return Err(ParseError::InvalidDataEntry(s.to_string())),
+            Some(variant) if variant.contains('/') => {
+                return Err(ParseError::InvalidDataEntry(s.to_string()))
+            }
+            Some(variant) => Some(Cow::Owned(variant.to_string())),
+            None => None,
+        };
+        Ok(DataEntry {
+            variant,
+            langid: langid.parse()?,
+        })
+    }
+}
+
 impl DataEntry {
     /// Gets the standard path components of this DataEntry. These components should be used when
     /// persisting the DataEntry on the filesystem or in structured data.
@@ -121,6 +147,7 @@ fn test_to_string() {
     ];
     for cas in cases.iter() {
         assert_eq!(cas.expected, cas.data_entry.to_string());
+        assert_eq!(cas.data_entry, cas.expected.parse().unwrap());
         assert_eq!(
             cas.expected,
             cas.data_entry
@@ -131,3 +158,10 @@ fn test_to_string() {
         );
     }
 }
+
+#[test]
+fn test_from_str_invalid() {
+    for s in ["", "GBP/", "/en", "a/b/en", "GBP/not a langid"].iter() {
+        assert!(s.parse::<DataEntry>().is_err(), "{}", s);
+    }
+}
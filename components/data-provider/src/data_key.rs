@@ -1,23 +1,73 @@
+use crate::error::ParseError;
 use std::borrow::Borrow;
 use std::borrow::Cow;
 use std::fmt;
 use std::fmt::Write;
+use std::str::FromStr;
 use tinystr::TinyStr16;
 
+/// Returns whether the string can be used as a sub-category or private-use category name: it
+/// must have between 1 and 16 characters, each an ASCII letter, digit, hyphen, or underscore.
+const fn is_valid_name(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() || bytes.len() > 16 {
+        return false;
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if !(b.is_ascii_alphanumeric() || b == b'-' || b == b'_') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Builds a TinyStr16 from a name in a const context. When evaluated at compile time, an invalid
+/// name results in a compile error.
+const fn name_to_tinystr(s: &str) -> TinyStr16 {
+    if !is_valid_name(s) {
+        panic!("Data key names must have 1 to 16 ASCII letters, digits, hyphens, or underscores");
+    }
+    let bytes = s.as_bytes();
+    let mut word: u128 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        word |= (bytes[i] as u128) << (8 * i);
+        i += 1;
+    }
+    // Safe because the string is non-empty and ASCII, so it has no interior or leading NUL bytes.
+    unsafe { TinyStr16::new_unchecked(word) }
+}
+
 /// A top-level collection of related data keys.
 #[non_exhaustive]
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
 pub enum DataCategory {
     Decimal,
+    Fallback,
     Plurals,
     PrivateUse(TinyStr16),
 }
 
 impl DataCategory {
+    /// Creates a private-use DataCategory. Can be used in a const context, in which case an
+    /// invalid name is a compile error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is empty, longer than 16 characters, or contains characters other than
+    /// ASCII letters, digits, hyphens, and underscores.
+    pub const fn new_private_use(name: &str) -> Self {
+        DataCategory::PrivateUse(name_to_tinystr(name))
+    }
+
     /// Gets or builds a string form of this DataCategory.
     pub fn as_str(&self) -> Cow<'static, str> {
         match self {
             DataCategory::Decimal => Cow::Borrowed("decimal"),
+            DataCategory::Fallback => Cow::Borrowed("fallback"),
             DataCategory::Plurals => Cow::Borrowed("plurals"),
             DataCategory::PrivateUse(id) => {
                 let mut result = String::from("x-");
@@ -34,12 +84,32 @@ impl fmt::Display for DataCategory {
     }
 }
 
+impl FromStr for DataCategory {
+    type Err = ParseError;
+
+    /// Parses the string form of a DataCategory, the inverse of [`DataCategory::as_str`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "decimal" => Ok(DataCategory::Decimal),
+            "fallback" => Ok(DataCategory::Fallback),
+            "plurals" => Ok(DataCategory::Plurals),
+            _ => {
+                if let Some(name) = s.strip_prefix("x-") {
+                    if is_valid_name(name) {
+                        return Ok(DataCategory::new_private_use(name));
+                    }
+                }
+                Err(ParseError::InvalidCategory(s.to_string()))
+            }
+        }
+    }
+}
+
 /// A category, subcategory, and version, used for requesting data from a DataProvider.
 ///
-/// The fields in a DataKey should generally be known at compile time.
-///
-/// Use `icu_data_key!` as a shortcut to create data keys in code.
-#[derive(PartialEq, Copy, Clone)]
+/// The fields in a DataKey should generally be known at compile time. Data keys are usually
+/// declared as constants, using `icu_data_key!` or [`DataKey::new`].
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
 pub struct DataKey {
     pub category: DataCategory,
     pub sub_category: TinyStr16,
@@ -48,43 +118,56 @@ pub struct DataKey {
 
 /// Shortcut to construct a data key from a URI-like syntax.
 ///
+/// The data key is built at compile time, so the macro can be used in `const` items, and an
+/// invalid name is a compile error.
+///
 /// # Examples
 ///
 /// ```
 /// use icu_data_provider::icu_data_key;
+/// use icu_data_provider::prelude::*;
 ///
 /// // Data key to request version 1 of cardinal plural rules
-/// let data_key = icu_data_key!(plurals: cardinal@1);
+/// const CARDINAL_V1: DataKey = icu_data_key!(plurals: cardinal@1);
+/// ```
+///
+/// ```compile_fail
+/// use icu_data_provider::icu_data_key;
+///
+/// // Error: sub-category is longer than 16 characters
+/// let data_key = icu_data_key!(plurals: thissubcategoryistoolong@1);
 /// ```
 #[macro_export]
 macro_rules! icu_data_key {
     (decimal: $sub_category:tt @ $version:tt) => {
         icu_data_key!($crate::DataCategory::Decimal, $sub_category, $version)
     };
+    (fallback: $sub_category:tt @ $version:tt) => {
+        icu_data_key!($crate::DataCategory::Fallback, $sub_category, $version)
+    };
     (plurals: $sub_category:tt @ $version:tt) => {
         icu_data_key!($crate::DataCategory::Plurals, $sub_category, $version)
     };
     (x-$private_use:tt: $sub_category:tt @ $version:tt) => {
         icu_data_key!(
-            $crate::DataCategory::PrivateUse(stringify!($private_use).parse().unwrap()),
+            $crate::DataCategory::new_private_use(stringify!($private_use)),
             $sub_category,
             $version
         )
     };
-    ($category:expr, $sub_category:tt, $version:tt) => {
-        $crate::DataKey {
-            category: $category,
-            // TODO: Parse to TinyStr at compile time
-            sub_category: stringify!($sub_category).parse().unwrap(),
-            version: $version,
-        }
-    };
+    ($category:expr, $sub_category:tt, $version:tt) => {{
+        // Evaluate in a const item to force compile-time validation.
+        const DATA_KEY: $crate::DataKey =
+            $crate::DataKey::new($category, stringify!($sub_category), $version);
+        DATA_KEY
+    }};
 }
 
 #[cfg(test)]
 fn test_data_key_macro(category: DataCategory) {
     let data_key_1 = match category {
         DataCategory::Decimal => icu_data_key!(decimal: foo@1),
+        DataCategory::Fallback => icu_data_key!(fallback: foo@1),
         DataCategory::Plurals => icu_data_key!(plurals: foo@1),
         DataCategory::PrivateUse(_) => icu_data_key!(x-private: foo@1),
     };
@@ -99,6 +182,7 @@ fn test_data_key_macro(category: DataCategory) {
 #[test]
 fn test_all_data_key_macros() {
     test_data_key_macro(DataCategory::Decimal);
+    test_data_key_macro(DataCategory::Fallback);
     test_data_key_macro(DataCategory::Plurals);
     test_data_key_macro(DataCategory::PrivateUse("private".parse().unwrap()));
 }
@@ -124,7 +208,71 @@ impl fmt::Display for DataKey {
     }
 }
 
+impl FromStr for DataKey {
+    type Err = ParseError;
+
+    /// Parses a string of the form `category/sub_category@version`, the inverse of the Display
+    /// implementation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu_data_provider::prelude::*;
+    ///
+    /// let data_key: DataKey = "plurals/cardinal@1".parse().unwrap();
+    /// assert_eq!(data_key, icu_data_key!(plurals: cardinal@1));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidDataKey(s.to_string());
+        let mut parts = s.splitn(2, '/');
+        let category = parts.next().ok_or_else(invalid)?;
+        let sub_category_and_version = parts.next().ok_or_else(invalid)?;
+        let mut parts = sub_category_and_version.rsplitn(2, '@');
+        let version = parts.next().ok_or_else(invalid)?;
+        let sub_category = parts.next().ok_or_else(invalid)?;
+        if !is_valid_name(sub_category) {
+            return Err(invalid());
+        }
+        Ok(DataKey::new(
+            category.parse()?,
+            sub_category,
+            version.parse().map_err(|_| invalid())?,
+        ))
+    }
+}
+
 impl DataKey {
+    /// Creates a DataKey. Can be used in a const context, in which case an invalid sub-category
+    /// is a compile error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-category is empty, longer than 16 characters, or contains characters
+    /// other than ASCII letters, digits, hyphens, and underscores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_data_provider::prelude::*;
+    ///
+    /// const CARDINAL_V1: DataKey = DataKey::new(DataCategory::Plurals, "cardinal", 1);
+    /// assert_eq!(CARDINAL_V1, icu_data_key!(plurals: cardinal@1));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use icu_data_provider::prelude::*;
+    ///
+    /// // Error: "@" is not allowed in a sub-category
+    /// const BAD_KEY: DataKey = DataKey::new(DataCategory::Plurals, "cardinal@1", 1);
+    /// ```
+    pub const fn new(category: DataCategory, sub_category: &str, version: u32) -> Self {
+        DataKey {
+            category,
+            sub_category: name_to_tinystr(sub_category),
+            version,
+        }
+    }
+
     /// Gets the standard path components of this DataKey. These components should be used when
     /// persisting the DataKey on the filesystem or in structured data.
     ///
@@ -173,6 +321,51 @@ impl From<&DataKey> for DataKeyComponents {
     }
 }
 
+#[test]
+fn test_const_data_key() {
+    const DATA_KEY: DataKey = DataKey::new(DataCategory::new_private_use("acme"), "greeting_v2", 2);
+    assert_eq!(DATA_KEY, "x-acme/greeting_v2@2".parse().unwrap());
+    assert_eq!(DATA_KEY, icu_data_key!(x-acme: greeting_v2@2));
+}
+
+#[test]
+#[should_panic]
+fn test_data_key_new_invalid() {
+    let sub_category = String::from("cardinal/1");
+    DataKey::new(DataCategory::Plurals, &sub_category, 1);
+}
+
+#[test]
+fn test_from_str() {
+    let keys = [
+        icu_data_key!(decimal: symbols@1),
+        icu_data_key!(fallback: parents@1),
+        icu_data_key!(plurals: cardinal@1),
+        icu_data_key!(x-private: foo@12),
+    ];
+    for data_key in keys.iter() {
+        assert_eq!(*data_key, data_key.to_string().parse().unwrap());
+    }
+
+    let invalid = [
+        "plurals",
+        "plurals/cardinal",
+        "plurals/cardinal@",
+        "plurals/cardinal@x",
+        "plurals/@1",
+        "plurals/toolongsubcategoryname@1",
+        "foo/cardinal@1",
+        "x-/cardinal@1",
+    ];
+    for s in invalid.iter() {
+        assert!(s.parse::<DataKey>().is_err(), "{}", s);
+    }
+    assert_eq!(
+        "foo/cardinal@1".parse::<DataKey>(),
+        Err(ParseError::InvalidCategory("foo".to_string()))
+    );
+}
+
 #[test]
 fn test_to_string() {
     struct TestCase {
@@ -1,19 +1,23 @@
 use crate::cloneable_any::CloneableAny;
 use crate::data_entry::DataEntry;
 use crate::data_key::DataKey;
+use crate::error::ParseError;
+use crate::marker::Yokeable;
+use async_trait::async_trait;
 use icu_locale::LanguageIdentifier;
-use std::any::Any;
 use std::any::TypeId;
-use std::borrow::Borrow;
-use std::borrow::BorrowMut;
 use std::borrow::Cow;
 use std::fmt;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
 
 // Re-export Error so it can be referenced by "data_provider::Error"
 pub use crate::error::Error;
 
 /// A struct to request a certain hunk of data from a data provider.
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
 pub struct DataRequest {
     pub data_key: DataKey,
     pub data_entry: DataEntry,
@@ -25,19 +29,174 @@ impl fmt::Display for DataRequest {
     }
 }
 
+impl FromStr for DataRequest {
+    type Err = ParseError;
+
+    /// Parses a string of the form `category/sub_category@version/[variant/]langid`, the inverse
+    /// of the Display implementation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu_data_provider::prelude::*;
+    ///
+    /// let req: DataRequest = "plurals/cardinal@1/en-US".parse().unwrap();
+    /// assert_eq!(req.data_key, icu_data_key!(plurals: cardinal@1));
+    /// assert_eq!(req.data_entry.langid, "en-US");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidDataRequest(s.to_string());
+        // The DataKey has exactly two components.
+        let key_len = match s.match_indices('/').nth(1) {
+            Some((index, _)) => index,
+            None => return Err(invalid()),
+        };
+        Ok(DataRequest {
+            data_key: s[..key_len].parse()?,
+            data_entry: s[key_len + 1..].parse()?,
+        })
+    }
+}
+
+/// The payload of a DataResponse, which may be borrowed, owned, or shared between threads.
+#[derive(Debug)]
+enum DataPayload<'d> {
+    Borrowed(&'d dyn CloneableAny),
+    Owned(Box<dyn CloneableAny>),
+    Shared(Arc<dyn CloneableAny>),
+    Borrowing(BorrowingPayload<'d>),
+}
+
+/// An owned payload that borrows data for the lifetime `'d`, such as strings borrowed from a
+/// memory-mapped data blob. See [`DataResponseBuilder::with_borrowing_payload`].
+#[derive(Debug)]
+struct BorrowingPayload<'d> {
+    /// The payload, stored as its `'static` type. It must only be handed out through
+    /// [`Yokeable::transform`], which shortens the lifetime of the borrowed data.
+    payload: Box<dyn CloneableAny>,
+    /// A copy of the payload that does not borrow, made the first time the payload is accessed as
+    /// its `'static` type.
+    owned: OnceLock<Box<dyn CloneableAny>>,
+    /// Copies the payload into one that does not borrow.
+    to_owned: fn(&dyn CloneableAny) -> Box<dyn CloneableAny>,
+    _borrow: PhantomData<&'d [u8]>,
+}
+
+impl<'d> Clone for DataPayload<'d> {
+    fn clone(&self) -> Self {
+        match self {
+            DataPayload::Borrowed(borrowed) => DataPayload::Borrowed(*borrowed),
+            DataPayload::Owned(boxed) => DataPayload::Owned(boxed.as_ref().to_owned()),
+            DataPayload::Shared(arc) => DataPayload::Shared(arc.clone()),
+            DataPayload::Borrowing(borrowing) => DataPayload::Borrowing(BorrowingPayload {
+                payload: borrowing.payload.as_ref().to_owned(),
+                owned: OnceLock::new(),
+                to_owned: borrowing.to_owned,
+                _borrow: PhantomData,
+            }),
+        }
+    }
+}
+
+impl<'d> DataPayload<'d> {
+    /// Gets the payload as its `'static` type. A payload that borrows data is copied first.
+    fn get(&self) -> &dyn CloneableAny {
+        match self {
+            DataPayload::Borrowed(borrowed) => *borrowed,
+            DataPayload::Owned(boxed) => boxed.as_ref(),
+            DataPayload::Shared(arc) => arc.as_ref(),
+            DataPayload::Borrowing(borrowing) => borrowing
+                .owned
+                .get_or_init(|| (borrowing.to_owned)(borrowing.payload.as_ref()))
+                .as_ref(),
+        }
+    }
+
+    /// Gets the payload without copying data that it borrows. The result must not be handed out
+    /// as its `'static` type; see [`BorrowingPayload::payload`].
+    fn get_borrowing(&self) -> &dyn CloneableAny {
+        match self {
+            DataPayload::Borrowing(borrowing) => borrowing.payload.as_ref(),
+            _ => self.get(),
+        }
+    }
+
+    /// Converts the payload to one that is owned and does not borrow data.
+    fn into_owned(self) -> Box<dyn CloneableAny> {
+        match self {
+            DataPayload::Borrowed(borrowed) => borrowed.to_owned(),
+            DataPayload::Owned(boxed) => boxed,
+            DataPayload::Shared(arc) => arc.as_ref().to_owned(),
+            DataPayload::Borrowing(borrowing) => match borrowing.owned.into_inner() {
+                Some(owned) => owned,
+                None => (borrowing.to_owned)(borrowing.payload.as_ref()),
+            },
+        }
+    }
+
+    /// Converts the payload to an owned payload, copying it if it is borrowed, shared, or
+    /// borrows data.
+    fn to_mut(&mut self) -> &mut dyn CloneableAny {
+        if !matches!(self, DataPayload::Owned(_)) {
+            // Move the payload out, leaving a placeholder that is replaced right away.
+            let payload = std::mem::replace(self, DataPayload::Borrowed(&()));
+            *self = DataPayload::Owned(payload.into_owned());
+        }
+        match self {
+            DataPayload::Owned(boxed) => boxed.as_mut(),
+            _ => unreachable!("The payload was made owned above"),
+        }
+    }
+}
+
+/// Copies a payload of type Y that borrows data into one that does not. Used as
+/// [`BorrowingPayload::to_owned`].
+fn to_owned_erased<Y>(payload: &dyn CloneableAny) -> Box<dyn CloneableAny>
+where
+    Y: for<'a> Yokeable<'a> + Clone + erased_serde::Serialize + fmt::Debug + Send + Sync,
+{
+    let payload: &Y = payload
+        .as_any()
+        .downcast_ref()
+        .expect("The payload type is fixed on construction");
+    Box::new(Y::to_owned_payload(payload.transform()))
+}
+
+/// Information about the origin of the payload in a DataResponse.
+///
+/// Data providers fill in the fields they know about; the others are left as their defaults.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataResponseMetadata {
+    /// Where the payload was loaded from, such as a file path.
+    pub source: Option<Cow<'static, str>>,
+    /// The version of the data, such as the version of CLDR it was derived from.
+    pub data_version: Option<Cow<'static, str>>,
+    /// Whether the payload is for a different DataEntry than the one requested because locale
+    /// fallback was performed.
+    pub fallback_applied: bool,
+}
+
 /// A response object containing a data hunk ("payload").
+///
+/// DataResponse is `Send` and `Sync`, so it can be moved to or shared with other threads. To share
+/// a single payload between threads without cloning it, use [`DataResponse::take_shared_payload`]
+/// or [`DataResponseBuilder::with_shared_payload`].
 #[derive(Debug, Clone)]
 pub struct DataResponse<'d> {
     pub data_langid: LanguageIdentifier,
-    payload: Cow<'d, dyn CloneableAny>,
-    // source: Cow<'static, str>,
+    /// Information about where the payload came from, for debugging and diagnostics.
+    pub metadata: DataResponseMetadata,
+    payload: DataPayload<'d>,
 }
 
 impl<'d> DataResponse<'d> {
     /// Get an immutable reference to the payload in a Response object.
-    /// The payload may or may not be owned by the Response.
+    /// The payload may or may not be owned by the Response. If the payload borrows data, such as
+    /// strings from a memory-mapped data blob, the data is copied the first time this is called;
+    /// use [`TypedDataResponse::get`](crate::TypedDataResponse::get) to avoid the copy.
     pub fn borrow_payload<T: 'static>(&self) -> Result<&T, Error> {
-        let borrowed: &dyn CloneableAny = self.payload.borrow();
+        let borrowed: &dyn CloneableAny = self.payload.get();
         borrowed
             .as_any()
             .downcast_ref::<T>()
@@ -49,14 +208,30 @@ impl<'d> DataResponse<'d> {
 
     /// Get an immutable reference to the payload as an erased_serde::Serialize trait object.
     pub fn borrow_as_serialize(&self) -> &dyn erased_serde::Serialize {
-        let borrowed: &dyn CloneableAny = self.payload.borrow();
-        borrowed.as_serialize()
+        self.payload.get_borrowing().as_serialize()
+    }
+
+    /// Get an immutable reference to the payload of type Y, with the lifetime of any data it
+    /// borrows shortened to that of the reference. Does not copy borrowed data.
+    pub(crate) fn borrow_yokeable<Y>(&self) -> Result<&<Y as Yokeable<'_>>::Output, Error>
+    where
+        Y: for<'a> Yokeable<'a>,
+    {
+        let borrowed: &dyn CloneableAny = self.payload.get_borrowing();
+        borrowed
+            .as_any()
+            .downcast_ref::<Y>()
+            .map(|payload| payload.transform())
+            .ok_or_else(|| Error::MismatchedType {
+                actual: borrowed.as_any().type_id(),
+                generic: Some(TypeId::of::<Y>()),
+            })
     }
 
     /// Get a mutable reference to the payload in a Response object.
-    /// The payload may or may not be owned by the Response.
+    /// If the payload is borrowed, shared, or borrows data, it is copied first.
     pub fn borrow_payload_mut<T: 'static>(&mut self) -> Result<&mut T, Error> {
-        let borrowed_mut: &mut dyn CloneableAny = self.payload.to_mut().borrow_mut();
+        let borrowed_mut: &mut dyn CloneableAny = self.payload.to_mut();
         // TODO: If I move this into the lambda, I get E0502. Why?
         let type_id = borrowed_mut.as_any().type_id();
         borrowed_mut
@@ -69,29 +244,79 @@ impl<'d> DataResponse<'d> {
     }
 
     /// Take ownership of the payload from a Response object. Consumes the Response object.
+    /// If the payload is shared or borrows data, it is copied.
     pub fn take_payload<T: 'static + Clone>(self) -> Result<Cow<'d, T>, Error> {
         match self.payload {
-            Cow::Borrowed(borrowed) => match borrowed.as_any().downcast_ref::<T>() {
+            DataPayload::Borrowed(borrowed) => match borrowed.as_any().downcast_ref::<T>() {
                 Some(v) => Ok(Cow::Borrowed(v)),
                 None => Err(Error::MismatchedType {
                     actual: borrowed.as_any().type_id(),
                     generic: Some(TypeId::of::<T>()),
                 }),
             },
-            Cow::Owned(boxed) => match boxed.into_any().downcast::<T>() {
+            DataPayload::Shared(arc) => match arc.as_any().downcast_ref::<T>() {
+                Some(v) => Ok(Cow::Owned(v.clone())),
+                None => Err(Error::MismatchedType {
+                    actual: arc.as_any().type_id(),
+                    generic: Some(TypeId::of::<T>()),
+                }),
+            },
+            payload => match payload.into_owned().into_any().downcast::<T>() {
                 Ok(boxed_t) => Ok(Cow::Owned(*boxed_t)),
                 Err(boxed_any) => Err(Error::MismatchedType {
-                    actual: boxed_any.type_id(),
+                    actual: (*boxed_any).type_id(),
                     generic: Some(TypeId::of::<T>()),
                 }),
             },
         }
     }
 
+    /// Take the payload from a Response object as an Arc, so that it can be shared cheaply
+    /// between threads. Consumes the Response object.
+    ///
+    /// Owned payloads are moved into the Arc, and borrowed payloads and payloads that borrow data
+    /// are copied into it. Shared payloads are returned without copying.
+    pub fn take_shared_payload<T: 'static + Clone + Send + Sync>(self) -> Result<Arc<T>, Error> {
+        match self.payload {
+            DataPayload::Borrowed(borrowed) => match borrowed.as_any().downcast_ref::<T>() {
+                Some(v) => Ok(Arc::new(v.clone())),
+                None => Err(Error::MismatchedType {
+                    actual: borrowed.as_any().type_id(),
+                    generic: Some(TypeId::of::<T>()),
+                }),
+            },
+            DataPayload::Shared(arc) => match arc.into_any_arc().downcast::<T>() {
+                Ok(arc_t) => Ok(arc_t),
+                Err(arc_any) => Err(Error::MismatchedType {
+                    actual: (*arc_any).type_id(),
+                    generic: Some(TypeId::of::<T>()),
+                }),
+            },
+            payload => match payload.into_owned().into_any().downcast::<T>() {
+                Ok(boxed_t) => Ok(Arc::from(boxed_t)),
+                Err(boxed_any) => Err(Error::MismatchedType {
+                    actual: (*boxed_any).type_id(),
+                    generic: Some(TypeId::of::<T>()),
+                }),
+            },
+        }
+    }
+
+    /// Move an owned payload behind an Arc, so that clones of the response share it.
+    pub(crate) fn into_shared(self) -> Self {
+        match self.payload {
+            DataPayload::Owned(boxed) => DataResponse {
+                data_langid: self.data_langid,
+                metadata: self.metadata,
+                payload: DataPayload::Shared(Arc::from(boxed)),
+            },
+            _ => self,
+        }
+    }
+
     /// Get the TypeId of the payload.
     pub fn get_payload_type_id(&self) -> TypeId {
-        let borrowed: &dyn CloneableAny = self.payload.borrow();
-        borrowed.as_any().type_id()
+        self.payload.get_borrowing().as_any().type_id()
     }
 }
 
@@ -104,26 +329,69 @@ impl DataResponseBuilder {
     /// Construct a DataResponse from the builder, with owned data.
     /// Consumes both the builder and the data.
     /// Returns the 'static lifetime since there is no borrowed data.
-    pub fn with_owned_payload<T: 'static + Clone + erased_serde::Serialize + fmt::Debug>(
-        self,
-        t: T,
-    ) -> DataResponse<'static> {
+    pub fn with_owned_payload<T>(self, t: T) -> DataResponse<'static>
+    where
+        T: 'static + Clone + erased_serde::Serialize + fmt::Debug + Send + Sync,
+    {
         DataResponse {
             data_langid: self.data_langid,
-            payload: Cow::Owned(Box::new(t) as Box<dyn CloneableAny>),
+            metadata: DataResponseMetadata::default(),
+            payload: DataPayload::Owned(Box::new(t)),
         }
     }
 
     /// Construct a DataResponse from the builder, with borrowed data.
     /// Consumes the builder, but not the data.
     #[allow(clippy::needless_lifetimes)]
-    pub fn with_borrowed_payload<'d, T: 'static + Clone + erased_serde::Serialize + fmt::Debug>(
-        self,
-        t: &'d T,
-    ) -> DataResponse<'d> {
+    pub fn with_borrowed_payload<'d, T>(self, t: &'d T) -> DataResponse<'d>
+    where
+        T: 'static + Clone + erased_serde::Serialize + fmt::Debug + Send + Sync,
+    {
+        DataResponse {
+            data_langid: self.data_langid,
+            metadata: DataResponseMetadata::default(),
+            payload: DataPayload::Borrowed(t),
+        }
+    }
+
+    /// Construct a DataResponse from the builder, with owned data that borrows from a buffer for
+    /// the lifetime 'd, such as strings borrowed from a memory-mapped data blob. Consumes the
+    /// builder and the data.
+    ///
+    /// `Y` is the payload type with the `'static` lifetime. The payload is handed out without
+    /// copying the borrowed data by [`TypedDataResponse::get`](crate::TypedDataResponse::get);
+    /// methods that return the payload as its `'static` type copy the borrowed data first.
+    pub fn with_borrowing_payload<'d, Y>(self, t: <Y as Yokeable<'d>>::Output) -> DataResponse<'d>
+    where
+        Y: for<'a> Yokeable<'a> + Clone + erased_serde::Serialize + fmt::Debug + Send + Sync,
+    {
+        // Safety: Yokeable guarantees that Output is Y with the lifetime 'd instead of 'static, so
+        // the two types have the same layout. The payload is only handed out as Y after copying
+        // the borrowed data, and otherwise with a lifetime no longer than 'd.
+        let payload: Y = unsafe { std::mem::transmute_copy(&ManuallyDrop::new(t)) };
+        DataResponse {
+            data_langid: self.data_langid,
+            metadata: DataResponseMetadata::default(),
+            payload: DataPayload::Borrowing(BorrowingPayload {
+                payload: Box::new(payload),
+                owned: OnceLock::new(),
+                to_owned: to_owned_erased::<Y>,
+                _borrow: PhantomData,
+            }),
+        }
+    }
+
+    /// Construct a DataResponse from the builder, with data shared through an Arc.
+    /// Consumes the builder. Cloning the response does not clone the data.
+    /// Returns the 'static lifetime since there is no borrowed data.
+    pub fn with_shared_payload<T>(self, t: Arc<T>) -> DataResponse<'static>
+    where
+        T: 'static + Clone + erased_serde::Serialize + fmt::Debug + Send + Sync,
+    {
         DataResponse {
             data_langid: self.data_langid,
-            payload: Cow::Borrowed(t),
+            metadata: DataResponseMetadata::default(),
+            payload: DataPayload::Shared(t),
         }
     }
 }
@@ -135,8 +403,8 @@ impl DataResponseBuilder {
 /// Note: 'd and 'a can be the same, but they do not need to be. For example, 'd = 'static if:
 /// 1. The provider always returns data that lives in static memory
 /// 2. The provider always returns owned data, not borrowed data
-// TODO: Make this async
-// #[async_trait]
+///
+/// Also see [`AsyncDataProvider`] for providers that cannot block the calling thread.
 pub trait DataProvider<'d> {
     /// Query the provider for data. Returns Ok if the request successfully loaded data. If data
     /// failed to load, returns an Error with more information.
@@ -158,3 +426,131 @@ impl<'d> dyn DataProvider<'d> + 'd {
         }
     }
 }
+
+/// An abstract data provider whose load operation does not block the calling thread. It is
+/// equivalent to [`DataProvider`], except that `load_async` returns a Future.
+///
+/// The Future is `Send`, so it can be spawned on a multi-threaded executor. This requires the
+/// provider to be `Sync`.
+///
+/// To use a synchronous [`DataProvider`] where an AsyncDataProvider is expected, wrap it in an
+/// [`AsyncAdapter`].
+#[async_trait]
+pub trait AsyncDataProvider<'d>: Sync {
+    /// Query the provider for data. Resolves to Ok if the request successfully loaded data. If
+    /// data failed to load, resolves to an Error with more information.
+    async fn load_async(&self, req: &DataRequest) -> Result<DataResponse<'d>, Error>;
+}
+
+/// An adapter exposing a synchronous [`DataProvider`] as an [`AsyncDataProvider`].
+///
+/// The synchronous `load` function is called when the Future is polled, so it blocks the
+/// executor thread while it runs. This is appropriate for providers that return data from memory,
+/// but not for providers performing I/O.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::AsyncAdapter;
+///
+/// async fn load_in_background<'d>(
+///     provider: &dyn AsyncDataProvider<'d>,
+///     req: &DataRequest,
+/// ) -> Result<DataResponse<'d>, DataError> {
+///     provider.load_async(req).await
+/// }
+///
+/// struct MyProvider;
+///
+/// impl DataProvider<'static> for MyProvider {
+///     fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+///         Err(DataError::UnavailableEntry(req.clone()))
+///     }
+/// }
+///
+/// let provider = AsyncAdapter::new(MyProvider);
+/// let req = DataRequest {
+///     data_key: icu_data_key!(plurals: cardinal@1),
+///     data_entry: DataEntry {
+///         variant: None,
+///         langid: "en".parse().unwrap(),
+///     },
+/// };
+/// let result = futures::executor::block_on(load_in_background(&provider, &req));
+/// assert!(matches!(result, Err(DataError::UnavailableEntry(_))));
+/// ```
+#[derive(Debug)]
+pub struct AsyncAdapter<P> {
+    inner: P,
+}
+
+impl<P> AsyncAdapter<P> {
+    /// Wrap a synchronous data provider.
+    pub fn new(inner: P) -> Self {
+        Self { inner }
+    }
+
+    /// Unwrap the synchronous data provider. Consumes the AsyncAdapter.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<'d, P> AsyncDataProvider<'d> for AsyncAdapter<P>
+where
+    P: DataProvider<'d> + Sync,
+{
+    async fn load_async(&self, req: &DataRequest) -> Result<DataResponse<'d>, Error> {
+        self.inner.load(req)
+    }
+}
+
+#[test]
+fn test_data_request_from_str() {
+    let cases = [
+        "plurals/cardinal@1/und",
+        "plurals/cardinal@1/sr-Latn",
+        "x-private/foo@2/GBP/en-ZA",
+    ];
+    for s in cases.iter() {
+        let req: DataRequest = s.parse().unwrap();
+        assert_eq!(*s, req.to_string());
+    }
+
+    for s in ["plurals/cardinal@1", "plurals/cardinal@1/", "und"].iter() {
+        assert!(s.parse::<DataRequest>().is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn test_borrowing_payload() {
+    use crate::structs::plurals::{CardinalV1Marker, PluralRuleStringsV1};
+    use crate::TypedDataResponse;
+
+    let data = String::from("i = 1");
+    let response = DataResponseBuilder {
+        data_langid: "en".parse().unwrap(),
+    }
+    .with_borrowing_payload::<PluralRuleStringsV1<'static>>(PluralRuleStringsV1 {
+        zero: None,
+        one: Some(Cow::Borrowed(&data)),
+        two: None,
+        few: None,
+        many: None,
+    });
+
+    // The typed payload borrows the data.
+    let typed = TypedDataResponse::<CardinalV1Marker>::try_from_response(response.clone()).unwrap();
+    assert!(matches!(typed.get().one, Some(Cow::Borrowed("i = 1"))));
+
+    // Accessing the payload as its 'static type copies the data.
+    let rules: &PluralRuleStringsV1 = response.borrow_payload().unwrap();
+    assert!(matches!(rules.one, Some(Cow::Owned(_))));
+    let mut cloned = response.clone();
+    let rules: &mut PluralRuleStringsV1 = cloned.borrow_payload_mut().unwrap();
+    assert!(matches!(rules.one, Some(Cow::Owned(_))));
+    let rules: Cow<PluralRuleStringsV1> = response.take_payload().unwrap();
+    assert!(matches!(rules.into_owned().one, Some(Cow::Owned(_))));
+}
@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use core::ops::Deref;
+use icu_locale::LanguageIdentifier;
 use std::any::TypeId;
 use std::fmt;
 
@@ -26,7 +27,61 @@ pub enum Error {
     },
 
     /// The data provider encountered some other error when loading the resource, such as I/O.
-    ResourceError(Box<dyn std::error::Error>),
+    ResourceError(Box<dyn std::error::Error + Send + Sync>),
+
+    /// The parent locales table makes the locale fallback chain loop back to this language
+    /// identifier.
+    FallbackCycle(LanguageIdentifier),
+
+    /// The parent locales table is not strictly sorted by child at this language identifier.
+    UnsortedParents(LanguageIdentifier),
+}
+
+/// An error when parsing a data provider type, such as a DataKey, from a string.
+#[non_exhaustive]
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    /// The string is not a known DataCategory or a valid private-use category.
+    InvalidCategory(String),
+
+    /// The string is not of the form `category/sub_category@version`.
+    InvalidDataKey(String),
+
+    /// The string is not of the form `[variant/]langid`.
+    InvalidDataEntry(String),
+
+    /// The string is not of the form `category/sub_category@version/[variant/]langid`.
+    InvalidDataRequest(String),
+
+    /// The language identifier in the string could not be parsed.
+    InvalidLangId(icu_locale::ParserError),
+}
+
+impl From<icu_locale::ParserError> for ParseError {
+    fn from(err: icu_locale::ParserError) -> Self {
+        ParseError::InvalidLangId(err)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidCategory(s) => write!(f, "Invalid data category: {}", s),
+            ParseError::InvalidDataKey(s) => write!(f, "Invalid data key: {}", s),
+            ParseError::InvalidDataEntry(s) => write!(f, "Invalid data entry: {}", s),
+            ParseError::InvalidDataRequest(s) => write!(f, "Invalid data request: {}", s),
+            ParseError::InvalidLangId(err) => write!(f, "Invalid language identifier: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::InvalidLangId(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl From<&DataKey> for Error {
@@ -47,8 +102,8 @@ impl From<DataRequest> for Error {
     }
 }
 
-impl From<Box<dyn std::error::Error>> for Error {
-    fn from(err: Box<dyn std::error::Error>) -> Self {
+impl From<Box<dyn std::error::Error + Send + Sync>> for Error {
+    fn from(err: Box<dyn std::error::Error + Send + Sync>) -> Self {
         Error::ResourceError(err)
     }
 }
@@ -56,7 +111,7 @@ impl From<Box<dyn std::error::Error>> for Error {
 impl Error {
     pub fn new_resc_error<T>(err: T) -> Self
     where
-        T: 'static + std::error::Error,
+        T: 'static + std::error::Error + Send + Sync,
     {
         Error::ResourceError(Box::new(err))
     }
@@ -76,6 +131,12 @@ impl fmt::Display for Error {
             }
             Error::UnavailableEntry(request) => write!(f, "Unavailable data entry: {}", request),
             Error::ResourceError(err) => write!(f, "Failed to load resource: {}", err),
+            Error::FallbackCycle(langid) => {
+                write!(f, "Cycle in the parent locales table at: {}", langid)
+            }
+            Error::UnsortedParents(langid) => {
+                write!(f, "Parent locales table is not sorted at: {}", langid)
+            }
         }
     }
 }
@@ -0,0 +1,444 @@
+use crate::error::Error;
+use crate::iter::DataEntryCollection;
+use crate::prelude::*;
+use crate::structs::fallback::{ParentLocalesV1, ParentLocalesV1Marker};
+use icu_locale::LanguageIdentifier;
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+/// A data provider that adds locale fallback to another data provider.
+///
+/// If the inner provider does not have data for the requested DataEntry (it returns
+/// `UnavailableEntry`), the request is retried with successively less specific language
+/// identifiers, removing one subtag at a time:
+///
+/// `sr-Latn-RS` → `sr-Latn` → `sr` → `und`
+///
+/// Before removing a subtag, the parent locales table (`fallback/parents@1`) is consulted, if
+/// available. This handles locales like `es-MX`, whose parent is `es-419`, and `zh-Hant`, whose
+/// parent is `und` rather than `zh`. Use [`LocaleFallbackProvider::try_new`] to load the table
+/// from the inner provider. Tables that would make the fallback chain loop are rejected with
+/// `FallbackCycle`.
+///
+/// If the DataEntry has a variant, the whole language identifier chain is tried with that
+/// variant before falling back to a less specific variant. Variants fall back by removing
+/// hyphen-separated segments from the end, and finally by removing the variant altogether.
+///
+/// The `data_langid` of the response is set to the language identifier that supplied the data,
+/// and `metadata.fallback_applied` is set if the data is for a different DataEntry than the one
+/// requested, so callers can tell when they received fallback data.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::LocaleFallbackProvider;
+///
+/// /// A provider that only has data for "sr".
+/// struct SerbianProvider;
+///
+/// impl DataProvider<'static> for SerbianProvider {
+///     fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+///         if req.data_entry.langid != "sr" {
+///             return Err(DataError::UnavailableEntry(req.clone()));
+///         }
+///         Ok(DataResponseBuilder {
+///             data_langid: req.data_entry.langid.clone(),
+///         }
+///         .with_owned_payload("Serbian".to_string()))
+///     }
+/// }
+///
+/// let provider = LocaleFallbackProvider::new(SerbianProvider);
+/// let response = provider
+///     .load(&DataRequest {
+///         data_key: icu_data_key!(plurals: cardinal@1),
+///         data_entry: DataEntry {
+///             variant: None,
+///             langid: "sr-Latn-RS".parse().unwrap(),
+///         },
+///     })
+///     .unwrap();
+///
+/// assert_eq!(response.data_langid, "sr");
+/// assert_eq!(response.borrow_payload::<String>().unwrap(), "Serbian");
+/// ```
+#[derive(Debug)]
+pub struct LocaleFallbackProvider<P> {
+    inner: P,
+    parents: Option<ParentLocalesV1>,
+}
+
+impl<P> LocaleFallbackProvider<P> {
+    /// Wrap a data provider, adding locale fallback to it. Only subtag truncation is performed;
+    /// parent locales are not consulted.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            parents: None,
+        }
+    }
+
+    /// Wrap a data provider, adding locale fallback to it that consults the given parent locales
+    /// before truncating subtags. Returns `UnsortedParents` if the parent locales are not sorted
+    /// by child, or `FallbackCycle` if they would make the fallback chain loop.
+    pub fn try_new_with_parents(inner: P, parents: ParentLocalesV1) -> Result<Self, Error> {
+        check_parents(&parents)?;
+        Ok(Self {
+            inner,
+            parents: Some(parents),
+        })
+    }
+
+    /// Get a reference to the wrapped data provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Unwrap the inner data provider. Consumes the LocaleFallbackProvider.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<'d, P> LocaleFallbackProvider<P>
+where
+    P: DataProvider<'d>,
+{
+    /// Wrap a data provider, adding locale fallback to it. The parent locales are loaded from the
+    /// inner provider. If the inner provider does not have parent locales data, only subtag
+    /// truncation is performed.
+    pub fn try_new(inner: P) -> Result<Self, Error> {
+        let parents = load_parents(&inner)?;
+        Ok(Self { inner, parents })
+    }
+}
+
+/// Loads the parent locales table from a data provider, and checks it with [`check_parents`].
+/// Returns None if the data provider does not have parent locales data.
+pub(crate) fn load_parents<'d, P>(provider: &P) -> Result<Option<ParentLocalesV1>, Error>
+where
+    P: DataProvider<'d>,
+{
+    let data_entry = DataEntry {
+        variant: None,
+        langid: LanguageIdentifier::default(),
+    };
+    match provider.load_typed::<ParentLocalesV1Marker>(&data_entry) {
+        Ok(response) => {
+            let parents = response.take_payload().into_owned();
+            check_parents(&parents)?;
+            Ok(Some(parents))
+        }
+        Err(Error::UnsupportedCategory(_)) => Ok(None),
+        Err(Error::UnsupportedDataKey(_)) => Ok(None),
+        Err(Error::UnavailableEntry(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Replaces the language identifier with its parent according to the parent locales table, or
+/// removes its most specific subtag. Returns false if the language identifier is already "und".
+pub(crate) fn fallback_langid(
+    langid: &mut LanguageIdentifier,
+    parents: Option<&ParentLocalesV1>,
+) -> bool {
+    if let Some(parent) = parents.and_then(|parents| parents.get_parent(langid)) {
+        *langid = parent.clone();
+        return true;
+    }
+    if !langid.variants.is_empty() {
+        langid.variants.clear();
+    } else if langid.region.is_some() {
+        langid.region = None;
+    } else if langid.script.is_some() {
+        langid.script = None;
+    } else if !langid.language.is_empty() {
+        langid.language.clear();
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Checks that the parent locales table is strictly sorted by child, as required by
+/// [`ParentLocalesV1::get_parent`], and that it does not make the fallback chain of any language
+/// identifier loop, which would make [`fallback_langid`] return true forever.
+pub(crate) fn check_parents(parents: &ParentLocalesV1) -> Result<(), Error> {
+    // The table may come from untrusted data, so do not assume that it is sorted.
+    if let Some(pair) = parents
+        .parents
+        .windows(2)
+        .find(|pair| pair[0].0 >= pair[1].0)
+    {
+        return Err(Error::UnsortedParents(pair[1].0.clone()));
+    }
+    // Removing subtags always shortens the language identifier, so any cycle goes through the
+    // table, and is found by following the chain of each child in the table.
+    for (child, _) in parents.parents.iter() {
+        let mut langid = child.clone();
+        let mut visited = HashSet::new();
+        visited.insert(langid.clone());
+        while fallback_langid(&mut langid, Some(parents)) {
+            if !visited.insert(langid.clone()) {
+                return Err(Error::FallbackCycle(langid));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Removes the last hyphen-separated segment of the variant, or the variant itself if it has
+/// only one segment. Returns false if there is no variant.
+fn truncate_variant(variant: &mut Option<Cow<'static, str>>) -> bool {
+    match variant {
+        Some(value) => {
+            match value.rfind('-') {
+                Some(idx) => value.to_mut().truncate(idx),
+                None => *variant = None,
+            };
+            true
+        }
+        None => false,
+    }
+}
+
+/// Moves the DataEntry to the next candidate in the fallback chain. Returns false if the chain
+/// has been exhausted.
+fn fallback_step(
+    data_entry: &mut DataEntry,
+    requested_langid: &LanguageIdentifier,
+    parents: Option<&ParentLocalesV1>,
+) -> bool {
+    if fallback_langid(&mut data_entry.langid, parents) {
+        return true;
+    }
+    if truncate_variant(&mut data_entry.variant) {
+        data_entry.langid = requested_langid.clone();
+        return true;
+    }
+    false
+}
+
+impl<'d, P> DataProvider<'d> for LocaleFallbackProvider<P>
+where
+    P: DataProvider<'d>,
+{
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, Error> {
+        let mut candidate = req.clone();
+        loop {
+            match self.inner.load(&candidate) {
+                Ok(mut response) => {
+                    response.metadata.fallback_applied = candidate.data_entry != req.data_entry;
+                    response.data_langid = candidate.data_entry.langid;
+                    return Ok(response);
+                }
+                Err(Error::UnavailableEntry(_)) => (),
+                Err(err) => return Err(err),
+            };
+            if !fallback_step(
+                &mut candidate.data_entry,
+                &req.data_entry.langid,
+                self.parents.as_ref(),
+            ) {
+                return Err(Error::UnavailableEntry(req.clone()));
+            }
+        }
+    }
+}
+
+impl<P> DataEntryCollection for LocaleFallbackProvider<P>
+where
+    P: DataEntryCollection,
+{
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, Error> {
+        self.inner.iter_for_key(data_key)
+    }
+}
+
+#[cfg(test)]
+struct TestProvider {
+    entries: Vec<&'static str>,
+}
+
+#[cfg(test)]
+impl DataProvider<'static> for TestProvider {
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, Error> {
+        if req.data_key.category != DataCategory::Plurals {
+            return Err(Error::UnsupportedCategory(req.data_key.category));
+        }
+        let entry_str = req.data_entry.to_string();
+        if !self.entries.contains(&entry_str.as_str()) {
+            return Err(Error::UnavailableEntry(req.clone()));
+        }
+        Ok(DataResponseBuilder {
+            data_langid: req.data_entry.langid.clone(),
+        }
+        .with_owned_payload(entry_str))
+    }
+}
+
+#[cfg(test)]
+fn make_request(variant: Option<&'static str>, langid: &str) -> DataRequest {
+    DataRequest {
+        data_key: icu_data_key!(plurals: cardinal@1),
+        data_entry: DataEntry {
+            variant: variant.map(Cow::Borrowed),
+            langid: langid.parse().unwrap(),
+        },
+    }
+}
+
+#[cfg(test)]
+fn load_with_fallback(
+    entries: Vec<&'static str>,
+    variant: Option<&'static str>,
+    langid: &str,
+) -> (String, LanguageIdentifier) {
+    let provider = LocaleFallbackProvider::new(TestProvider { entries });
+    let response = provider.load(&make_request(variant, langid)).unwrap();
+    let payload: &String = response.borrow_payload().unwrap();
+    (payload.clone(), response.data_langid)
+}
+
+#[test]
+fn test_langid_fallback() {
+    let entries = vec!["sr", "und"];
+    let (payload, data_langid) = load_with_fallback(entries.clone(), None, "sr-Latn-RS");
+    assert_eq!(payload, "sr");
+    assert_eq!(data_langid, "sr");
+
+    let (payload, data_langid) = load_with_fallback(entries.clone(), None, "sr");
+    assert_eq!(payload, "sr");
+    assert_eq!(data_langid, "sr");
+
+    let (payload, data_langid) = load_with_fallback(entries, None, "de-CH-1996");
+    assert_eq!(payload, "und");
+    assert_eq!(data_langid, "und");
+}
+
+#[test]
+fn test_variant_fallback() {
+    let entries = vec!["GBP/en", "en-GB", "und"];
+    let (payload, _) = load_with_fallback(entries.clone(), Some("GBP"), "en-GB");
+    assert_eq!(payload, "GBP/en");
+
+    // Variant fallback is also reported in the metadata.
+    let provider = LocaleFallbackProvider::new(TestProvider {
+        entries: entries.clone(),
+    });
+    let response = provider.load(&make_request(Some("GBP-x"), "en")).unwrap();
+    assert!(response.metadata.fallback_applied);
+
+    let (payload, _) = load_with_fallback(entries.clone(), Some("GBP-x"), "en");
+    assert_eq!(payload, "GBP/en");
+
+    let (payload, data_langid) = load_with_fallback(entries, Some("USD"), "en-GB");
+    assert_eq!(payload, "en-GB");
+    assert_eq!(data_langid, "en-GB");
+}
+
+#[test]
+fn test_parent_locales_fallback() {
+    let parents = ParentLocalesV1 {
+        parents: vec![
+            ("es-MX".parse().unwrap(), "es-419".parse().unwrap()),
+            ("zh-Hant".parse().unwrap(), "und".parse().unwrap()),
+        ],
+    };
+    let provider = LocaleFallbackProvider::try_new_with_parents(
+        TestProvider {
+            entries: vec!["es-419", "es", "zh", "und"],
+        },
+        parents,
+    )
+    .unwrap();
+
+    let response = provider.load(&make_request(None, "es-MX")).unwrap();
+    assert_eq!(response.data_langid, "es-419");
+    assert!(response.metadata.fallback_applied);
+
+    let response = provider.load(&make_request(None, "es-419")).unwrap();
+    assert!(!response.metadata.fallback_applied);
+
+    let response = provider.load(&make_request(None, "es-ES")).unwrap();
+    assert_eq!(response.data_langid, "es");
+
+    let response = provider.load(&make_request(None, "zh-Hant-TW")).unwrap();
+    assert_eq!(response.data_langid, "und");
+
+    let response = provider.load(&make_request(None, "zh-Hans-CN")).unwrap();
+    assert_eq!(response.data_langid, "zh");
+}
+
+#[test]
+fn test_fallback_errors() {
+    let provider = LocaleFallbackProvider::new(TestProvider {
+        entries: vec!["sr"],
+    });
+    match provider.load(&make_request(None, "de-CH")) {
+        Err(Error::UnavailableEntry(req)) => {
+            // The error should report the original request, not the last fallback candidate.
+            assert_eq!(req.data_entry.langid, "de-CH");
+        }
+        other => panic!("Expected UnavailableEntry, got: {:?}", other),
+    };
+
+    let result = provider.load(&DataRequest {
+        data_key: icu_data_key!(decimal: symbols@1),
+        data_entry: DataEntry {
+            variant: None,
+            langid: "sr".parse().unwrap(),
+        },
+    });
+    assert!(matches!(result, Err(Error::UnsupportedCategory(_))));
+}
+
+#[test]
+fn test_parent_locales_cycle() {
+    // Sorted by child, as required by get_parent.
+    let parents = ParentLocalesV1 {
+        parents: vec![
+            ("es-419".parse().unwrap(), "es-MX".parse().unwrap()),
+            ("es-MX".parse().unwrap(), "es-419".parse().unwrap()),
+        ],
+    };
+    let result =
+        LocaleFallbackProvider::try_new_with_parents(TestProvider { entries: vec![] }, parents);
+    assert!(matches!(result, Err(Error::FallbackCycle(_))));
+
+    // The cycle can also go through subtag truncation: "es-MX" → "es" → "es-MX".
+    let parents = ParentLocalesV1 {
+        parents: vec![("es".parse().unwrap(), "es-MX".parse().unwrap())],
+    };
+    let result =
+        LocaleFallbackProvider::try_new_with_parents(TestProvider { entries: vec![] }, parents);
+    assert!(matches!(result, Err(Error::FallbackCycle(_))));
+}
+
+#[test]
+fn test_parent_locales_unsorted() {
+    let parents = ParentLocalesV1 {
+        parents: vec![
+            ("es-MX".parse().unwrap(), "es-419".parse().unwrap()),
+            ("es-419".parse().unwrap(), "es".parse().unwrap()),
+        ],
+    };
+    let result =
+        LocaleFallbackProvider::try_new_with_parents(TestProvider { entries: vec![] }, parents);
+    assert!(matches!(result, Err(Error::UnsortedParents(langid)) if langid == "es-419"));
+
+    // Duplicate children are rejected too, since get_parent would pick either one.
+    let parents = ParentLocalesV1 {
+        parents: vec![
+            ("es-MX".parse().unwrap(), "es-419".parse().unwrap()),
+            ("es-MX".parse().unwrap(), "es".parse().unwrap()),
+        ],
+    };
+    let result =
+        LocaleFallbackProvider::try_new_with_parents(TestProvider { entries: vec![] }, parents);
+    assert!(matches!(result, Err(Error::UnsortedParents(_))));
+}
@@ -0,0 +1,257 @@
+use crate::error::Error;
+use crate::fallback::{check_parents, fallback_langid, load_parents};
+use crate::iter::DataEntryCollection;
+use crate::prelude::*;
+use crate::structs::fallback::ParentLocalesV1;
+use icu_locale::LanguageIdentifier;
+use std::collections::HashSet;
+
+/// A data provider that restricts another data provider to an allow-list of language
+/// identifiers.
+///
+/// Requests for any other language identifier return `UnavailableEntry` without querying the
+/// inner provider, and `iter_for_key` omits their DataEntries. Wrapping the source provider in a
+/// LocaleFilterProvider before exporting it slices the exported data to the locales an
+/// application supports. Variants are not filtered.
+///
+/// Use [`LocaleFilterProvider::try_new_with_ancestors`] to also allow the locale fallback
+/// ancestors of each language identifier, including "und". A [`LocaleFallbackProvider`] reading
+/// the filtered data then finds the same data for the allowed language identifiers as it would
+/// with the complete data.
+///
+/// Data that does not vary by locale, such as the parent locales table, is stored under "und",
+/// so "und" must be allowed to export it.
+///
+/// [`LocaleFallbackProvider`]: crate::LocaleFallbackProvider
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::iter::DataEntryCollection;
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::LocaleFilterProvider;
+///
+/// /// A provider that has data for every locale, but only lists "de", "en", and "fr".
+/// struct EchoProvider;
+///
+/// impl DataProvider<'static> for EchoProvider {
+///     fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+///         Ok(DataResponseBuilder {
+///             data_langid: req.data_entry.langid.clone(),
+///         }
+///         .with_owned_payload(req.data_entry.to_string()))
+///     }
+/// }
+///
+/// impl DataEntryCollection for EchoProvider {
+///     fn iter_for_key(
+///         &self,
+///         _data_key: &DataKey,
+///     ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {
+///         let list: Vec<DataEntry> = ["de", "en", "fr"]
+///             .iter()
+///             .map(|s| s.parse().unwrap())
+///             .collect();
+///         Ok(Box::new(list.into_iter()))
+///     }
+/// }
+///
+/// let provider =
+///     LocaleFilterProvider::new(EchoProvider, vec!["en".parse().unwrap(), "fr".parse().unwrap()]);
+/// let data_key = icu_data_key!(plurals: cardinal@1);
+///
+/// let entries: Vec<String> = provider
+///     .iter_for_key(&data_key)
+///     .unwrap()
+///     .map(|entry| entry.to_string())
+///     .collect();
+/// assert_eq!(entries, vec!["en", "fr"]);
+///
+/// let result = provider.load(&DataRequest {
+///     data_key,
+///     data_entry: "de".parse().unwrap(),
+/// });
+/// assert!(matches!(result, Err(DataError::UnavailableEntry(_))));
+/// ```
+#[derive(Debug)]
+pub struct LocaleFilterProvider<P> {
+    inner: P,
+    allowed: HashSet<LanguageIdentifier>,
+}
+
+impl<P> LocaleFilterProvider<P> {
+    /// Wrap a data provider, allowing only the given language identifiers.
+    pub fn new<I>(inner: P, langids: I) -> Self
+    where
+        I: IntoIterator<Item = LanguageIdentifier>,
+    {
+        Self {
+            inner,
+            allowed: langids.into_iter().collect(),
+        }
+    }
+
+    /// Wrap a data provider, allowing the given language identifiers along with their locale
+    /// fallback ancestors according to the given parent locales table and subtag truncation.
+    /// Returns `UnsortedParents` if the parent locales are not sorted by child, or
+    /// `FallbackCycle` if they would make the fallback chain loop.
+    pub fn try_new_with_parents<I>(
+        inner: P,
+        langids: I,
+        parents: Option<&ParentLocalesV1>,
+    ) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = LanguageIdentifier>,
+    {
+        if let Some(parents) = parents {
+            check_parents(parents)?;
+        }
+        let mut allowed = HashSet::new();
+        for mut langid in langids {
+            allowed.insert(langid.clone());
+            while fallback_langid(&mut langid, parents) {
+                allowed.insert(langid.clone());
+            }
+        }
+        Ok(Self { inner, allowed })
+    }
+
+    /// Returns whether data for the language identifier passes through the filter.
+    pub fn is_allowed(&self, langid: &LanguageIdentifier) -> bool {
+        self.allowed.contains(langid)
+    }
+
+    /// Get a reference to the wrapped data provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Unwrap the inner data provider. Consumes the LocaleFilterProvider.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+}
+
+impl<'d, P> LocaleFilterProvider<P>
+where
+    P: DataProvider<'d>,
+{
+    /// Wrap a data provider, allowing the given language identifiers along with their locale
+    /// fallback ancestors. The parent locales are loaded from the inner provider. If the inner
+    /// provider does not have parent locales data, only subtag truncation is performed.
+    pub fn try_new_with_ancestors<I>(inner: P, langids: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = LanguageIdentifier>,
+    {
+        let parents = load_parents(&inner)?;
+        Self::try_new_with_parents(inner, langids, parents.as_ref())
+    }
+}
+
+impl<'d, P> DataProvider<'d> for LocaleFilterProvider<P>
+where
+    P: DataProvider<'d>,
+{
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, Error> {
+        if !self.is_allowed(&req.data_entry.langid) {
+            return Err(Error::UnavailableEntry(req.clone()));
+        }
+        self.inner.load(req)
+    }
+}
+
+impl<P> DataEntryCollection for LocaleFilterProvider<P>
+where
+    P: DataEntryCollection,
+{
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, Error> {
+        let list: Vec<DataEntry> = self
+            .inner
+            .iter_for_key(data_key)?
+            .filter(|entry| self.is_allowed(&entry.langid))
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[cfg(test)]
+fn make_parents() -> ParentLocalesV1 {
+    // Sorted by child, as required by get_parent.
+    ParentLocalesV1 {
+        parents: vec![
+            ("es-419".parse().unwrap(), "es".parse().unwrap()),
+            ("es-MX".parse().unwrap(), "es-419".parse().unwrap()),
+        ],
+    }
+}
+
+#[cfg(test)]
+fn get_allowed<P>(provider: &LocaleFilterProvider<P>) -> Vec<String> {
+    let mut allowed: Vec<String> = provider.allowed.iter().map(|l| l.to_string()).collect();
+    allowed.sort();
+    allowed
+}
+
+#[test]
+fn test_exact() {
+    let provider = LocaleFilterProvider::new((), vec!["sr-Latn-RS".parse().unwrap()]);
+    assert_eq!(get_allowed(&provider), vec!["sr-Latn-RS"]);
+    assert!(!provider.is_allowed(&"sr".parse().unwrap()));
+}
+
+#[test]
+fn test_ancestors() {
+    let provider = LocaleFilterProvider::try_new_with_parents(
+        (),
+        vec!["sr-Latn-RS".parse().unwrap(), "es-MX".parse().unwrap()],
+        Some(&make_parents()),
+    )
+    .unwrap();
+    assert_eq!(
+        get_allowed(&provider),
+        vec![
+            "es",
+            "es-419",
+            "es-MX",
+            "sr",
+            "sr-Latn",
+            "sr-Latn-RS",
+            "und"
+        ]
+    );
+
+    let provider =
+        LocaleFilterProvider::try_new_with_parents((), vec!["es-MX".parse().unwrap()], None)
+            .unwrap();
+    assert_eq!(get_allowed(&provider), vec!["es", "es-MX", "und"]);
+}
+
+#[cfg(feature = "invariant")]
+#[test]
+fn test_filtered_load() {
+    use crate::structs::plurals::PluralRuleStringsV1;
+    let provider = LocaleFilterProvider::new(
+        crate::InvariantDataProvider,
+        vec![LanguageIdentifier::default()],
+    );
+    let mut req = DataRequest {
+        data_key: icu_data_key!(plurals: cardinal@1),
+        data_entry: DataEntry {
+            variant: None,
+            langid: LanguageIdentifier::default(),
+        },
+    };
+    let response = provider.load(&req).unwrap();
+    let _: &PluralRuleStringsV1 = response.borrow_payload().unwrap();
+    let entries: Vec<DataEntry> = provider.iter_for_key(&req.data_key).unwrap().collect();
+    assert_eq!(entries.len(), 1);
+
+    req.data_entry.langid = "en".parse().unwrap();
+    assert!(matches!(
+        provider.load(&req),
+        Err(Error::UnavailableEntry(_))
+    ));
+}
@@ -8,7 +8,7 @@ use std::fmt;
 /// Package a data struct T implementing Default as a DataResponse.
 pub(crate) fn make_inv_response<T>() -> Option<DataResponse<'static>>
 where
-    T: 'static + Clone + erased_serde::Serialize + fmt::Debug + Default,
+    T: 'static + Clone + erased_serde::Serialize + fmt::Debug + Send + Sync + Default,
 {
     Some(
         DataResponseBuilder {
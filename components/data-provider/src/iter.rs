@@ -23,7 +23,7 @@ pub trait DataExporter {
         &mut self,
         req: &DataRequest,
         obj: &dyn erased_serde::Serialize,
-    ) -> Result<(), Box<dyn std::error::Error>>;
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 }
 
 impl<'d, T> IterableDataProvider<'d> for T
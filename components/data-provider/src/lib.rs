@@ -12,6 +12,10 @@
 //! "plurals/cardinal@1") and [`DataEntry`] (a language identifier and optional variant, e.g.,
 //! "fr") being requested. The Response contains the data payload corresponding to the Request.
 //!
+//! Each DataKey corresponds to a specific Rust type for its payload. The [`DataMarker`] types in
+//! the [`structs`] module encode that correspondence, allowing data to be loaded with compile-time
+//! type checking via [`TypedDataProvider::load_typed`].
+//!
 //! The most common types required for ICU4X DataProvider are included via the prelude:
 //!
 //! ```
@@ -32,6 +36,19 @@
 //! assert_eq!("plurals/cardinal@1", icu_data_key!(plurals: cardinal@1).to_string());
 //! ```
 //!
+//! ## Async Data Providers
+//!
+//! Data providers that perform I/O may implement [`AsyncDataProvider`] so that loading data
+//! does not block an executor thread. A synchronous DataProvider can be used where an
+//! AsyncDataProvider is expected by wrapping it in an [`AsyncAdapter`].
+//!
+//! ## Thread Safety
+//!
+//! Data payloads and errors are `Send + Sync`, so a DataResponse can be moved to another thread.
+//! A payload can be shared between threads without cloning it by taking it as an `Arc` with
+//! [`DataResponse::take_shared_payload`], or by constructing a response around an existing `Arc`
+//! with [`DataResponseBuilder::with_shared_payload`].
+//!
 //! ## Types of Data Providers
 //!
 //! Any object implementing DataProvider can be used to supply ICU4X with locale data. ICU4X ships
@@ -39,6 +56,8 @@
 //!
 //! - [`FsDataProvider`][icu_fs_data_provider::FsDataProvider] reads structured data from the
 //!   filesystem. It can also write out that filesystem structure.
+//! - [`BlobDataProvider`][icu_blob_data_provider::BlobDataProvider] reads structured data from a
+//!   single indexed data blob, which can be embedded in the binary.
 //! - [`CldrJsonDataProvider`][icu_cldr_json_data_provider::CldrJsonDataProvider] reads structured
 //!   data directly from CLDR source files.
 //!
@@ -49,23 +68,66 @@
 //! instances supported for a certain key in the data provider. This can be useful when
 //! transforming data between storage formats. For more information, see the [`iter`] module.
 //!
+//! ## Locale Fallback
+//!
+//! Data providers generally return `UnavailableEntry` if they do not have data for the exact
+//! DataEntry being requested. Wrap a data provider in [`LocaleFallbackProvider`] to retry
+//! requests with less specific language identifiers, such as "sr-Latn-RS" → "sr-Latn" → "sr" →
+//! "und".
+//!
+//! ## Data Slicing
+//!
+//! Wrap a data provider in [`LocaleFilterProvider`] to restrict it to the language identifiers
+//! an application supports, optionally along with their fallback ancestors. Exporting the
+//! filtered data provider writes only the data for those locales. To find out which data keys
+//! and locales an application uses, wrap its data provider in [`RecordingDataProvider`].
+//!
+//! ## Combining Data Providers
+//!
+//! Data from several sources, such as CLDR data and private-use data, can be combined into a
+//! single data provider. [`ChainProvider`] tries data providers in order, skipping those that do
+//! not support the requested key or do not have the requested entry, while [`ForkByKeyProvider`] routes each request to a data
+//! provider based on its DataKey. Both merge the entries of their data providers, so the combined
+//! data provider can still be exported.
+//!
+//! ## Caching
+//!
+//! Wrap a data provider in [`CachingDataProvider`] to keep recently loaded responses in memory,
+//! avoiding repeated I/O and deserialization when the same data is requested more than once.
+//! See [`CacheOptions`] for the capacity and eviction policy.
+//!
 //! ## InvariantDataProvider
 //!
 //! For testing or development purposes, this crate also offers [`InvariantDataProvider`], which
 //! returns fixed data that does not vary by locale. You must enable InvariantDataProvider via the
 //! `"invariant"` feature in your Cargo.toml file.
 
+pub mod bake;
+mod caching;
 mod cloneable_any;
+mod combinators;
 mod data_entry;
 mod data_key;
 mod data_provider;
 mod error;
+mod fallback;
+mod filter;
 pub mod iter;
+mod marker;
+mod recording;
 pub mod structs;
 
 #[cfg(feature = "invariant")]
 mod invariant;
 
+pub use caching::{CacheOptions, CacheStats, CachingDataProvider, EvictionPolicy};
+pub use combinators::{ChainProvider, ForkByKeyProvider, ForkRule};
+pub use data_provider::AsyncAdapter;
+pub use error::ParseError;
+pub use fallback::LocaleFallbackProvider;
+pub use filter::LocaleFilterProvider;
+pub use recording::{RecordedOutcome, RecordedRequest, RecordingDataProvider};
+
 #[cfg(feature = "invariant")]
 pub use invariant::InvariantDataProvider;
 
@@ -73,12 +135,18 @@ pub mod prelude {
     pub use crate::data_entry::DataEntry;
     pub use crate::data_key::DataCategory;
     pub use crate::data_key::DataKey;
+    pub use crate::data_provider::AsyncDataProvider;
     pub use crate::data_provider::DataProvider;
     pub use crate::data_provider::DataRequest;
     pub use crate::data_provider::DataResponse;
     pub use crate::data_provider::DataResponseBuilder;
+    pub use crate::data_provider::DataResponseMetadata;
     pub use crate::error::Error as DataError;
     pub use crate::icu_data_key;
+    pub use crate::marker::DataMarker;
+    pub use crate::marker::TypedDataProvider;
+    pub use crate::marker::TypedDataResponse;
+    pub use crate::marker::Yokeable;
 }
 
 // Also include the same symbols at the top level for selective inclusion
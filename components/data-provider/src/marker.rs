@@ -0,0 +1,173 @@
+use crate::error::Error;
+use crate::prelude::*;
+use icu_locale::LanguageIdentifier;
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A marker type binding a DataKey to the Rust type of its payload.
+///
+/// Marker types are zero-sized structs defined next to the data structs in the [`structs`]
+/// module. Loading data through a marker with [`TypedDataProvider::load_typed`] guarantees at
+/// compile time that the key being requested and the type being read agree with each other.
+///
+/// [`structs`]: crate::structs
+pub trait DataMarker {
+    /// The type of the data payload for this key.
+    type Yokeable: for<'a> Yokeable<'a> + Clone + erased_serde::Serialize + fmt::Debug + Send + Sync;
+
+    /// The DataKey corresponding to this marker.
+    const KEY: DataKey;
+}
+
+/// A data payload type whose data may be borrowed, such as strings borrowed from a memory-mapped
+/// data blob.
+///
+/// `Self` is the payload type with the `'static` lifetime, which is how payloads are stored in a
+/// DataResponse, and `Output` is the same type with the lifetime `'a`, which is how payloads that
+/// borrow data are handed out. Payload types without a lifetime parameter use `Self` as `Output`.
+/// See [`DataResponseBuilder::with_borrowing_payload`].
+///
+/// # Safety
+///
+/// `Output` must be `Self` with its lifetime parameter, if any, replaced by `'a`, and
+/// `to_owned_payload` must return a payload that does not borrow from its argument.
+pub unsafe trait Yokeable<'a>: 'static {
+    /// The payload type with the lifetime `'a`.
+    type Output: 'a;
+
+    /// Shortens the lifetime of the borrowed data. Implement as `self`, which compiles only if the
+    /// payload type is covariant in its lifetime.
+    fn transform(&'a self) -> &'a Self::Output;
+
+    /// Copies any borrowed data, so that the payload can outlive the data it borrowed from.
+    fn to_owned_payload(output: &Self::Output) -> Self;
+}
+
+/// Implements [`Yokeable`] for payload types without a lifetime parameter.
+macro_rules! impl_yokeable_for_owned {
+    ($type:ty) => {
+        // Safety: the type has no lifetime parameter, so Output is Self.
+        unsafe impl<'a> $crate::marker::Yokeable<'a> for $type {
+            type Output = $type;
+
+            fn transform(&'a self) -> &'a Self::Output {
+                self
+            }
+
+            fn to_owned_payload(output: &Self::Output) -> Self {
+                output.clone()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_yokeable_for_owned;
+
+/// A response object whose payload is known to be of type `M::Yokeable`.
+///
+/// Obtain one from [`TypedDataProvider::load_typed`].
+pub struct TypedDataResponse<'d, M: DataMarker> {
+    response: DataResponse<'d>,
+    _marker: PhantomData<M>,
+}
+
+impl<'d, M: DataMarker> fmt::Debug for TypedDataResponse<'d, M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedDataResponse")
+            .field("response", &self.response)
+            .finish()
+    }
+}
+
+impl<'d, M: DataMarker> TypedDataResponse<'d, M> {
+    /// Wrap a DataResponse, checking that its payload is of type `M::Yokeable`.
+    pub fn try_from_response(response: DataResponse<'d>) -> Result<Self, Error> {
+        response.borrow_yokeable::<M::Yokeable>()?;
+        Ok(Self {
+            response,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get an immutable reference to the payload. If the payload borrows data, such as strings
+    /// from a memory-mapped data blob, the data is not copied.
+    pub fn get(&self) -> &<M::Yokeable as Yokeable<'_>>::Output {
+        self.response
+            .borrow_yokeable::<M::Yokeable>()
+            .expect("The payload type is checked on construction")
+    }
+
+    /// Get a mutable reference to the payload. If the payload is borrowed or borrows data, it is
+    /// copied first.
+    pub fn get_mut(&mut self) -> &mut M::Yokeable {
+        self.response
+            .borrow_payload_mut()
+            .expect("The payload type is checked on construction")
+    }
+
+    /// Take ownership of the payload. Consumes the TypedDataResponse. If the payload borrows data,
+    /// the data is copied.
+    pub fn take_payload(self) -> Cow<'d, M::Yokeable> {
+        self.response
+            .take_payload()
+            .expect("The payload type is checked on construction")
+    }
+
+    /// Get the language identifier of the data that was actually loaded.
+    pub fn data_langid(&self) -> &LanguageIdentifier {
+        &self.response.data_langid
+    }
+
+    /// Unwrap the type-erased DataResponse.
+    pub fn into_response(self) -> DataResponse<'d> {
+        self.response
+    }
+}
+
+/// Auto-implemented trait: A data provider that can be queried with a [`DataMarker`] instead of
+/// a DataKey.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::structs::plurals::CardinalV1Marker;
+///
+/// fn get_rule_for_one<'d>(provider: &impl DataProvider<'d>) -> Option<String> {
+///     let response = provider
+///         .load_typed::<CardinalV1Marker>(&DataEntry {
+///             variant: None,
+///             langid: "en".parse().unwrap(),
+///         })
+///         .ok()?;
+///     // No type annotations needed: the payload is a PluralRuleStringsV1.
+///     response.get().one.as_ref().map(|rule| rule.to_string())
+/// }
+/// ```
+pub trait TypedDataProvider<'d> {
+    /// Query the provider for data of the type bound to the marker `M`.
+    ///
+    /// Returns `Error::MismatchedType` if the provider returns a payload of a type other than
+    /// `M::Yokeable`, which indicates a bug in the data provider.
+    fn load_typed<M: DataMarker>(
+        &self,
+        data_entry: &DataEntry,
+    ) -> Result<TypedDataResponse<'d, M>, Error>;
+}
+
+impl<'d, P> TypedDataProvider<'d> for P
+where
+    P: DataProvider<'d> + ?Sized,
+{
+    fn load_typed<M: DataMarker>(
+        &self,
+        data_entry: &DataEntry,
+    ) -> Result<TypedDataResponse<'d, M>, Error> {
+        let response = self.load(&DataRequest {
+            data_key: M::KEY,
+            data_entry: data_entry.clone(),
+        })?;
+        TypedDataResponse::try_from_response(response)
+    }
+}
@@ -0,0 +1,300 @@
+use crate::error::Error;
+use crate::iter::DataEntryCollection;
+use crate::prelude::*;
+use crate::structs;
+use icu_locale::LanguageIdentifier;
+use std::collections::HashSet;
+use std::io;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+/// The outcome of a DataRequest recorded by a RecordingDataProvider.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordedOutcome {
+    /// The request was successful.
+    Loaded {
+        /// The language identifier of the data that was loaded, which differs from the requested
+        /// one if locale fallback was applied.
+        data_langid: LanguageIdentifier,
+        /// Whether the response was for a different DataEntry than the one requested.
+        fallback_applied: bool,
+    },
+    /// The request failed with an error, given in its Display form.
+    Failed(String),
+}
+
+/// A DataRequest recorded by a RecordingDataProvider, along with its outcome.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecordedRequest {
+    pub request: DataRequest,
+    pub outcome: RecordedOutcome,
+}
+
+/// A data provider that records every DataRequest passed to another data provider.
+///
+/// Run an application with its data provider wrapped in a RecordingDataProvider to find out which
+/// data keys and locales it uses. The recorded data keys can be written to a key file with
+/// [`write_key_file`](RecordingDataProvider::write_key_file), which can be passed to
+/// `icu4x-cldr-export --key-file` to export only the data the application needs.
+///
+/// Each distinct request and outcome is recorded once. Wrap a [`LocaleFallbackProvider`] to
+/// record which locale supplied the data for each request.
+///
+/// RecordingDataProvider can be shared between threads if the inner data provider can.
+///
+/// [`LocaleFallbackProvider`]: crate::LocaleFallbackProvider
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::RecordingDataProvider;
+///
+/// struct MyProvider;
+///
+/// impl DataProvider<'static> for MyProvider {
+///     fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+///         Ok(DataResponseBuilder {
+///             data_langid: req.data_entry.langid.clone(),
+///         }
+///         .with_owned_payload("Hello".to_string()))
+///     }
+/// }
+///
+/// let provider = RecordingDataProvider::new(MyProvider);
+/// provider
+///     .load(&"plurals/cardinal@1/en".parse().unwrap())
+///     .unwrap();
+/// provider
+///     .load(&"plurals/ordinal@1/fr".parse().unwrap())
+///     .unwrap();
+///
+/// let mut key_file = Vec::new();
+/// provider.write_key_file(&mut key_file).unwrap();
+/// assert_eq!(
+///     String::from_utf8(key_file).unwrap(),
+///     "# Data keys recorded by RecordingDataProvider\n\
+///      plurals/cardinal@1\n\
+///      plurals/ordinal@1\n"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct RecordingDataProvider<P> {
+    inner: P,
+    requests: Mutex<HashSet<RecordedRequest>>,
+}
+
+impl<P> RecordingDataProvider<P> {
+    /// Wrap a data provider, recording the requests passed to it.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            requests: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Get the recorded requests, sorted by their string form.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        let mut requests: Vec<RecordedRequest> = self.lock_requests().iter().cloned().collect();
+        requests.sort_by_cached_key(|recorded| {
+            (
+                recorded.request.to_string(),
+                format!("{:?}", recorded.outcome),
+            )
+        });
+        requests
+    }
+
+    /// Get the distinct data keys of the recorded requests, whether or not they were successful,
+    /// sorted by their string form.
+    pub fn data_keys(&self) -> Vec<DataKey> {
+        let data_keys: HashSet<DataKey> = self
+            .lock_requests()
+            .iter()
+            .map(|recorded| recorded.request.data_key)
+            .collect();
+        let mut data_keys: Vec<DataKey> = data_keys.into_iter().collect();
+        data_keys.sort_by_cached_key(|data_key| data_key.to_string());
+        data_keys
+    }
+
+    /// Get the distinct language identifiers of the recorded requests, whether or not they were
+    /// successful, sorted by their string form.
+    pub fn langids(&self) -> Vec<LanguageIdentifier> {
+        let langids: HashSet<LanguageIdentifier> = self
+            .lock_requests()
+            .iter()
+            .map(|recorded| recorded.request.data_entry.langid.clone())
+            .collect();
+        let mut langids: Vec<LanguageIdentifier> = langids.into_iter().collect();
+        langids.sort_by_cached_key(|langid| langid.to_string());
+        langids
+    }
+
+    /// Writes the recorded data keys to a key file, one per line, in the format read by
+    /// `icu4x-cldr-export --key-file`. Data keys that are not known to ICU4X, such as private-use
+    /// keys, are written as comments, since they cannot be exported.
+    pub fn write_key_file<W: io::Write>(&self, mut sink: W) -> io::Result<()> {
+        let known_keys = structs::get_all_keys();
+        writeln!(sink, "# Data keys recorded by RecordingDataProvider")?;
+        for data_key in self.data_keys() {
+            if known_keys.contains(&data_key) {
+                writeln!(sink, "{}", data_key)?;
+            } else {
+                writeln!(sink, "# Unknown data key: {}", data_key)?;
+            }
+        }
+        sink.flush()
+    }
+
+    /// Remove all recorded requests.
+    pub fn clear(&self) {
+        self.lock_requests().clear();
+    }
+
+    /// Get a reference to the wrapped data provider.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Unwrap the inner data provider. Consumes the RecordingDataProvider.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn lock_requests(&self) -> MutexGuard<'_, HashSet<RecordedRequest>> {
+        // Each insertion is atomic, so the set is consistent even if another thread panicked
+        // while holding the lock.
+        self.requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<'d, P> DataProvider<'d> for RecordingDataProvider<P>
+where
+    P: DataProvider<'d>,
+{
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, Error> {
+        let result = self.inner.load(req);
+        let outcome = match &result {
+            Ok(response) => RecordedOutcome::Loaded {
+                data_langid: response.data_langid.clone(),
+                fallback_applied: response.metadata.fallback_applied,
+            },
+            Err(err) => RecordedOutcome::Failed(err.to_string()),
+        };
+        self.lock_requests().insert(RecordedRequest {
+            request: req.clone(),
+            outcome,
+        });
+        result
+    }
+}
+
+impl<P> DataEntryCollection for RecordingDataProvider<P>
+where
+    P: DataEntryCollection,
+{
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, Error> {
+        self.inner.iter_for_key(data_key)
+    }
+}
+
+#[cfg(test)]
+fn make_request(data_key: DataKey, langid: &str) -> DataRequest {
+    DataRequest {
+        data_key,
+        data_entry: DataEntry {
+            variant: None,
+            langid: langid.parse().unwrap(),
+        },
+    }
+}
+
+#[cfg(feature = "invariant")]
+#[test]
+fn test_recording() {
+    // InvariantDataProvider has data for every locale, so restrict it to "und" to test fallback.
+    let provider = RecordingDataProvider::new(crate::LocaleFallbackProvider::new(
+        crate::LocaleFilterProvider::new(
+            crate::InvariantDataProvider,
+            vec![LanguageIdentifier::default()],
+        ),
+    ));
+    let private_key = icu_data_key!(x-private: foo@1);
+    provider
+        .load(&make_request(structs::plurals::key::CARDINAL_V1, "en"))
+        .unwrap();
+    provider
+        .load(&make_request(structs::plurals::key::CARDINAL_V1, "en"))
+        .unwrap();
+    provider
+        .load(&make_request(structs::plurals::key::ORDINAL_V1, "und"))
+        .unwrap();
+    provider.load(&make_request(private_key, "fr")).unwrap_err();
+
+    let requests = provider.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(
+        requests[0].outcome,
+        RecordedOutcome::Loaded {
+            data_langid: LanguageIdentifier::default(),
+            fallback_applied: true,
+        }
+    );
+    assert_eq!(
+        requests[1].outcome,
+        RecordedOutcome::Loaded {
+            data_langid: LanguageIdentifier::default(),
+            fallback_applied: false,
+        }
+    );
+    assert!(matches!(requests[2].outcome, RecordedOutcome::Failed(_)));
+
+    let langids: Vec<String> = provider.langids().iter().map(|l| l.to_string()).collect();
+    assert_eq!(langids, vec!["en", "fr", "und"]);
+
+    let mut key_file = Vec::new();
+    provider.write_key_file(&mut key_file).unwrap();
+    assert_eq!(
+        String::from_utf8(key_file).unwrap(),
+        "# Data keys recorded by RecordingDataProvider\n\
+         plurals/cardinal@1\n\
+         plurals/ordinal@1\n\
+         # Unknown data key: x-private/foo@1\n"
+    );
+
+    provider.clear();
+    assert!(provider.requests().is_empty());
+}
+
+#[test]
+fn test_recording_threads() {
+    struct FailingProvider;
+    impl DataProvider<'static> for FailingProvider {
+        fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, Error> {
+            Err(Error::UnavailableEntry(req.clone()))
+        }
+    }
+    let provider = RecordingDataProvider::new(FailingProvider);
+    std::thread::scope(|scope| {
+        for langid in ["de", "en", "fr"].iter() {
+            let provider = &provider;
+            scope.spawn(move || {
+                provider
+                    .load(&make_request(structs::plurals::key::CARDINAL_V1, langid))
+                    .unwrap_err();
+            });
+        }
+    });
+    assert_eq!(provider.requests().len(), 3);
+    assert_eq!(
+        provider.data_keys(),
+        vec![structs::plurals::key::CARDINAL_V1]
+    );
+}
@@ -2,19 +2,57 @@
 use serde::{Deserialize, Serialize};
 use smallstr::SmallString;
 
-#[cfg(feature = "invariant")]
 use crate::prelude::*;
 
+/// Data keys defined in this module.
+pub mod key {
+    use crate::prelude::*;
+
+    /// Data key `decimal/symbols@1`.
+    pub const SYMBOLS_V1: DataKey = icu_data_key!(decimal: symbols@1);
+
+    /// All data keys defined in this module.
+    pub const ALL_KEYS: [DataKey; 1] = [SYMBOLS_V1];
+}
+
+/// Gets all data keys defined in this module.
+pub fn get_all_keys() -> Vec<DataKey> {
+    key::ALL_KEYS.to_vec()
+}
+
+/// Gets the function that deserializes the payload for a data key in this module's category.
+pub(crate) fn get_deserializer(data_key: &DataKey) -> Option<super::DeserializeFn> {
+    use super::deserialize_owned;
+    match *data_key {
+        key::SYMBOLS_V1 => Some(deserialize_owned::<SymbolsV1>),
+        _ => None,
+    }
+}
+
+/// Gets the function that deserializes the payload for a data key in this module's category,
+/// borrowing from the data. The payloads in this module do not borrow, so this is the same as
+/// [`get_deserializer`].
+pub(crate) fn get_borrowed_deserializer(
+    data_key: &DataKey,
+) -> Option<super::DeserializeBorrowedFn> {
+    get_deserializer(data_key).map(|deserialize| deserialize as super::DeserializeBorrowedFn)
+}
+
+/// Gets the function that bakes the payload for a data key in this module's category.
+pub(crate) fn get_baker(data_key: &DataKey) -> Option<crate::bake::BakeFn> {
+    use crate::bake::bake_deserialized;
+    match *data_key {
+        key::SYMBOLS_V1 => Some(bake_deserialized::<SymbolsV1>),
+        _ => None,
+    }
+}
+
 /// Gets a locale-invariant default struct given a data key in this module's category.
 #[cfg(feature = "invariant")]
 pub(crate) fn get_invariant(data_key: &DataKey) -> Option<DataResponse<'static>> {
     use crate::invariant::make_inv_response;
-    if data_key.category != DataCategory::Decimal {
-        return None;
-    }
-    // TODO(#212): Match on TinyStr instead of &str
-    match (data_key.sub_category.as_str(), data_key.version) {
-        ("symbols", 1) => make_inv_response::<SymbolsV1>(),
+    match *data_key {
+        key::SYMBOLS_V1 => make_inv_response::<SymbolsV1>(),
         _ => None,
     }
 }
@@ -26,6 +64,33 @@ pub struct SymbolsV1 {
     pub grouping_separator: SmallString<[u8; 8]>,
 }
 
+impl crate::bake::Bake for SymbolsV1 {
+    const TYPE_PATH: &'static str = "::icu_data_provider::structs::decimal::SymbolsV1";
+    // SmallString cannot be constructed in a constant expression.
+    const IS_CONST: bool = false;
+
+    fn bake(&self) -> String {
+        format!(
+            "{} {{ zero_digit: {:?}, decimal_separator: {:?}.into(), grouping_separator: {:?}.into() }}",
+            Self::TYPE_PATH,
+            self.zero_digit,
+            self.decimal_separator.as_str(),
+            self.grouping_separator.as_str(),
+        )
+    }
+}
+
+crate::marker::impl_yokeable_for_owned!(SymbolsV1);
+
+/// Marker type for the key `decimal/symbols@1`, whose payload is [`SymbolsV1`].
+pub struct SymbolsV1Marker;
+
+impl DataMarker for SymbolsV1Marker {
+    type Yokeable = SymbolsV1;
+
+    const KEY: DataKey = key::SYMBOLS_V1;
+}
+
 #[cfg(feature = "invariant")]
 impl Default for SymbolsV1 {
     fn default() -> Self {
@@ -0,0 +1,119 @@
+// Locale fallback types
+use icu_locale::LanguageIdentifier;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Data keys defined in this module.
+pub mod key {
+    use crate::prelude::*;
+
+    /// Data key `fallback/parents@1`.
+    pub const PARENTS_V1: DataKey = icu_data_key!(fallback: parents@1);
+
+    /// All data keys defined in this module.
+    pub const ALL_KEYS: [DataKey; 1] = [PARENTS_V1];
+}
+
+/// Gets all data keys defined in this module.
+pub fn get_all_keys() -> Vec<DataKey> {
+    key::ALL_KEYS.to_vec()
+}
+
+/// Gets the function that deserializes the payload for a data key in this module's category.
+pub(crate) fn get_deserializer(data_key: &DataKey) -> Option<super::DeserializeFn> {
+    use super::deserialize_owned;
+    match *data_key {
+        key::PARENTS_V1 => Some(deserialize_owned::<ParentLocalesV1>),
+        _ => None,
+    }
+}
+
+/// Gets the function that deserializes the payload for a data key in this module's category,
+/// borrowing from the data. The payloads in this module do not borrow, so this is the same as
+/// [`get_deserializer`].
+pub(crate) fn get_borrowed_deserializer(
+    data_key: &DataKey,
+) -> Option<super::DeserializeBorrowedFn> {
+    get_deserializer(data_key).map(|deserialize| deserialize as super::DeserializeBorrowedFn)
+}
+
+/// Gets the function that bakes the payload for a data key in this module's category.
+pub(crate) fn get_baker(data_key: &DataKey) -> Option<crate::bake::BakeFn> {
+    use crate::bake::bake_deserialized;
+    match *data_key {
+        key::PARENTS_V1 => Some(bake_deserialized::<ParentLocalesV1>),
+        _ => None,
+    }
+}
+
+/// Gets a locale-invariant default struct given a data key in this module's category.
+#[cfg(feature = "invariant")]
+pub(crate) fn get_invariant(data_key: &DataKey) -> Option<DataResponse<'static>> {
+    use crate::invariant::make_inv_response;
+    match *data_key {
+        key::PARENTS_V1 => make_inv_response::<ParentLocalesV1>(),
+        _ => None,
+    }
+}
+
+/// Parent locales that cannot be derived by removing subtags from the end of a language
+/// identifier. For example, the parent of "es-MX" is "es-419", and the parent of "zh-Hant" is
+/// "und" (root), not "zh".
+///
+/// The pairs are (child, parent), strictly sorted by child. The locale fallback providers return
+/// an error for a table that is not sorted.
+///
+/// More information: https://unicode.org/reports/tr35/#Parent_Locales
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "invariant", derive(Default))]
+pub struct ParentLocalesV1 {
+    pub parents: Vec<(LanguageIdentifier, LanguageIdentifier)>,
+}
+
+crate::marker::impl_yokeable_for_owned!(ParentLocalesV1);
+
+/// Marker type for the key `fallback/parents@1`, whose payload is [`ParentLocalesV1`].
+pub struct ParentLocalesV1Marker;
+
+impl DataMarker for ParentLocalesV1Marker {
+    type Yokeable = ParentLocalesV1;
+
+    const KEY: DataKey = key::PARENTS_V1;
+}
+
+impl ParentLocalesV1 {
+    /// Gets the explicit parent of a language identifier, if there is one. The result is
+    /// unspecified if the pairs are not sorted by child.
+    pub fn get_parent(&self, langid: &LanguageIdentifier) -> Option<&LanguageIdentifier> {
+        self.parents
+            .binary_search_by(|(child, _)| child.cmp(langid))
+            .ok()
+            .map(|idx| &self.parents[idx].1)
+    }
+}
+
+impl crate::bake::Bake for ParentLocalesV1 {
+    const TYPE_PATH: &'static str = "::icu_data_provider::structs::fallback::ParentLocalesV1";
+    // LanguageIdentifier cannot be constructed in a constant expression.
+    const IS_CONST: bool = false;
+
+    fn bake(&self) -> String {
+        let parents: Vec<String> = self
+            .parents
+            .iter()
+            .map(|(child, parent)| {
+                format!(
+                    "({:?}.parse().unwrap(), {:?}.parse().unwrap())",
+                    child.to_string(),
+                    parent.to_string()
+                )
+            })
+            .collect();
+        format!(
+            "{} {{ parents: vec![{}] }}",
+            Self::TYPE_PATH,
+            parents.join(", ")
+        )
+    }
+}
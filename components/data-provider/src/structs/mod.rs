@@ -1,8 +1,51 @@
 pub mod decimal;
+pub mod fallback;
 pub mod plurals;
 
-#[cfg(feature = "invariant")]
+use crate::error::Error;
 use crate::prelude::*;
+use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::fmt;
+
+/// A function that deserializes the payload for a particular data key and packages it, along
+/// with the language identifier from the builder, as a DataResponse.
+///
+/// Obtain the function for a data key with [`get_deserializer`].
+pub type DeserializeFn = for<'de> fn(
+    &mut dyn erased_serde::Deserializer<'de>,
+    DataResponseBuilder,
+) -> Result<DataResponse<'static>, erased_serde::Error>;
+
+/// Like [`DeserializeFn`], but the payload borrows from the data for the lifetime `'d`, such as
+/// from a data blob embedded in the binary or a memory-mapped file. Strings in the payload borrow
+/// from the data instead of being copied where the data format allows it.
+///
+/// Obtain the function for a data key with [`get_borrowed_deserializer`].
+pub type DeserializeBorrowedFn = for<'d> fn(
+    &mut dyn erased_serde::Deserializer<'d>,
+    DataResponseBuilder,
+) -> Result<DataResponse<'d>, erased_serde::Error>;
+
+/// Gets all data keys defined in the `structs` module. This is the registry of all data keys
+/// known to ICU4X.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::structs;
+///
+/// let data_key: DataKey = "plurals/cardinal@1".parse().unwrap();
+/// assert!(structs::get_all_keys().contains(&data_key));
+/// ```
+pub fn get_all_keys() -> Vec<DataKey> {
+    let mut result: Vec<DataKey> = vec![];
+    result.extend(decimal::get_all_keys());
+    result.extend(fallback::get_all_keys());
+    result.extend(plurals::get_all_keys());
+    result
+}
 
 /// Gets a locale-invariant default struct given a data key in this module's category.
 /// For example, if the data key is `plurals/cardinal@1`, a Response with an object of type
@@ -11,5 +54,183 @@ use crate::prelude::*;
 pub(crate) fn get_invariant(data_key: &DataKey) -> Option<DataResponse<'static>> {
     None //
         .or_else(|| decimal::get_invariant(data_key)) //
+        .or_else(|| fallback::get_invariant(data_key)) //
         .or_else(|| plurals::get_invariant(data_key)) //
 }
+
+/// Deserializes a data struct T as an owned payload. Used as the [`DeserializeFn`] for each data
+/// key in this module.
+pub(crate) fn deserialize_owned<T>(
+    deserializer: &mut dyn erased_serde::Deserializer,
+    builder: DataResponseBuilder,
+) -> Result<DataResponse<'static>, erased_serde::Error>
+where
+    T: 'static + Clone + DeserializeOwned + erased_serde::Serialize + fmt::Debug + Send + Sync,
+{
+    let obj: T = erased_serde::deserialize(deserializer)?;
+    Ok(builder.with_owned_payload(obj))
+}
+
+/// Deserializes an optional string, borrowing it from the data if the data format allows it.
+/// For use with `#[serde(deserialize_with)]`.
+pub(crate) fn deserialize_option_cow_str<'de, D>(
+    deserializer: D,
+) -> Result<Option<Cow<'de, str>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Visitor;
+    use std::marker::PhantomData;
+
+    struct StrVisitor<'de>(PhantomData<&'de str>);
+
+    impl<'de> Visitor<'de> for StrVisitor<'de> {
+        type Value = Cow<'de, str>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+            Ok(Cow::Borrowed(v))
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v.to_owned()))
+        }
+
+        fn visit_string<E: serde::de::Error>(self, v: String) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(v))
+        }
+    }
+
+    struct OptionVisitor<'de>(PhantomData<&'de str>);
+
+    impl<'de> Visitor<'de> for OptionVisitor<'de> {
+        type Value = Option<Cow<'de, str>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an optional string")
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer
+                .deserialize_str(StrVisitor(PhantomData))
+                .map(Some)
+        }
+    }
+
+    deserializer.deserialize_option(OptionVisitor(PhantomData))
+}
+
+/// Gets the function that deserializes the payload for a data key defined in the `structs`
+/// module. Returns None if the data key is unknown.
+///
+/// This registry allows data providers that read serialized data, such as from the filesystem,
+/// to support every data key without dispatching on the category themselves.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::structs;
+///
+/// let json = r#"{"zero_digit":"0","decimal_separator":".","grouping_separator":","}"#;
+/// let deserialize = structs::get_deserializer(&structs::decimal::key::SYMBOLS_V1).unwrap();
+/// let mut deserializer = serde_json::Deserializer::from_str(json);
+/// let response = deserialize(
+///     &mut <dyn erased_serde::Deserializer>::erase(&mut deserializer),
+///     DataResponseBuilder {
+///         data_langid: "en".parse().unwrap(),
+///     },
+/// )
+/// .unwrap();
+/// let symbols: &structs::decimal::SymbolsV1 = response.borrow_payload().unwrap();
+/// assert_eq!(symbols.decimal_separator, ".");
+/// ```
+pub fn get_deserializer(data_key: &DataKey) -> Option<DeserializeFn> {
+    None //
+        .or_else(|| decimal::get_deserializer(data_key)) //
+        .or_else(|| fallback::get_deserializer(data_key)) //
+        .or_else(|| plurals::get_deserializer(data_key)) //
+}
+
+/// Gets the function that deserializes the payload for a data key defined in the `structs`
+/// module, borrowing from the data. Returns None if the data key is unknown.
+pub fn get_borrowed_deserializer(data_key: &DataKey) -> Option<DeserializeBorrowedFn> {
+    None //
+        .or_else(|| decimal::get_borrowed_deserializer(data_key)) //
+        .or_else(|| fallback::get_borrowed_deserializer(data_key)) //
+        .or_else(|| plurals::get_borrowed_deserializer(data_key)) //
+}
+
+/// Gets the function that bakes the payload for a data key defined in the `structs` module into
+/// Rust source code. Returns None if the data key is unknown. See the [`bake`](crate::bake)
+/// module.
+pub fn get_baker(data_key: &DataKey) -> Option<crate::bake::BakeFn> {
+    None //
+        .or_else(|| decimal::get_baker(data_key)) //
+        .or_else(|| fallback::get_baker(data_key)) //
+        .or_else(|| plurals::get_baker(data_key)) //
+}
+
+/// Deserializes the payload for a data key using the function from [`get_deserializer`].
+///
+/// Returns `UnsupportedDataKey` if the data key is unknown, or `ResourceError` if the data does
+/// not match the struct for the data key.
+pub fn deserialize_response(
+    data_key: &DataKey,
+    builder: DataResponseBuilder,
+    deserializer: &mut dyn erased_serde::Deserializer,
+) -> Result<DataResponse<'static>, Error> {
+    let deserialize = get_deserializer(data_key).ok_or_else(|| Error::from(data_key))?;
+    deserialize(deserializer, builder).map_err(|err| Error::ResourceError(Box::new(err)))
+}
+
+/// Deserializes the payload for a data key using the function from
+/// [`get_borrowed_deserializer`], so that the payload borrows from the data for the lifetime
+/// `'d`.
+///
+/// Returns `UnsupportedDataKey` if the data key is unknown, or `ResourceError` if the data does
+/// not match the struct for the data key.
+///
+/// # Example
+///
+/// ```
+/// use icu_data_provider::prelude::*;
+/// use icu_data_provider::structs::{self, plurals::CardinalV1Marker};
+/// use std::borrow::Cow;
+///
+/// let json = String::from(r#"{"zero":null,"one":"i = 1","two":null,"few":null,"many":null}"#);
+/// let mut deserializer = serde_json::Deserializer::from_str(&json);
+/// let response = structs::deserialize_borrowed_response(
+///     &structs::plurals::key::CARDINAL_V1,
+///     DataResponseBuilder {
+///         data_langid: "en".parse().unwrap(),
+///     },
+///     &mut <dyn erased_serde::Deserializer>::erase(&mut deserializer),
+/// )
+/// .unwrap();
+/// let response = TypedDataResponse::<CardinalV1Marker>::try_from_response(response).unwrap();
+/// // The string was not copied.
+/// assert!(matches!(response.get().one, Some(Cow::Borrowed("i = 1"))));
+/// ```
+pub fn deserialize_borrowed_response<'d>(
+    data_key: &DataKey,
+    builder: DataResponseBuilder,
+    deserializer: &mut dyn erased_serde::Deserializer<'d>,
+) -> Result<DataResponse<'d>, Error> {
+    let deserialize = get_borrowed_deserializer(data_key).ok_or_else(|| Error::from(data_key))?;
+    deserialize(deserializer, builder).map_err(|err| Error::ResourceError(Box::new(err)))
+}
@@ -2,20 +2,76 @@
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
-#[cfg(feature = "invariant")]
 use crate::prelude::*;
 
+/// Data keys defined in this module.
+pub mod key {
+    use crate::prelude::*;
+
+    /// Data key `plurals/cardinal@1`.
+    pub const CARDINAL_V1: DataKey = icu_data_key!(plurals: cardinal@1);
+
+    /// Data key `plurals/ordinal@1`.
+    pub const ORDINAL_V1: DataKey = icu_data_key!(plurals: ordinal@1);
+
+    /// All data keys defined in this module.
+    pub const ALL_KEYS: [DataKey; 2] = [CARDINAL_V1, ORDINAL_V1];
+}
+
+/// Gets all data keys defined in this module.
+pub fn get_all_keys() -> Vec<DataKey> {
+    key::ALL_KEYS.to_vec()
+}
+
+/// Gets the function that deserializes the payload for a data key in this module's category.
+pub(crate) fn get_deserializer(data_key: &DataKey) -> Option<super::DeserializeFn> {
+    use super::deserialize_owned;
+    match *data_key {
+        key::CARDINAL_V1 => Some(deserialize_owned::<PluralRuleStringsV1>),
+        key::ORDINAL_V1 => Some(deserialize_owned::<PluralRuleStringsV1>),
+        _ => None,
+    }
+}
+
+/// Gets the function that deserializes the payload for a data key in this module's category,
+/// borrowing from the data.
+pub(crate) fn get_borrowed_deserializer(
+    data_key: &DataKey,
+) -> Option<super::DeserializeBorrowedFn> {
+    match *data_key {
+        key::CARDINAL_V1 => Some(deserialize_borrowed_rules),
+        key::ORDINAL_V1 => Some(deserialize_borrowed_rules),
+        _ => None,
+    }
+}
+
+/// Deserializes plural rule strings, borrowing the strings from the data if the data format
+/// allows it.
+fn deserialize_borrowed_rules<'d>(
+    deserializer: &mut dyn erased_serde::Deserializer<'d>,
+    builder: DataResponseBuilder,
+) -> Result<DataResponse<'d>, erased_serde::Error> {
+    let rules: BorrowedPluralRuleStringsV1 = erased_serde::deserialize(deserializer)?;
+    Ok(builder.with_borrowing_payload::<PluralRuleStringsV1<'static>>(rules.into()))
+}
+
+/// Gets the function that bakes the payload for a data key in this module's category.
+pub(crate) fn get_baker(data_key: &DataKey) -> Option<crate::bake::BakeFn> {
+    use crate::bake::bake_deserialized;
+    match *data_key {
+        key::CARDINAL_V1 => Some(bake_deserialized::<PluralRuleStringsV1>),
+        key::ORDINAL_V1 => Some(bake_deserialized::<PluralRuleStringsV1>),
+        _ => None,
+    }
+}
+
 /// Gets a locale-invariant default struct given a data key in this module's category.
 #[cfg(feature = "invariant")]
 pub(crate) fn get_invariant(data_key: &DataKey) -> Option<DataResponse<'static>> {
     use crate::invariant::make_inv_response;
-    if data_key.category != DataCategory::Plurals {
-        return None;
-    }
-    // TODO(#212): Match on TinyStr instead of &str
-    match (data_key.sub_category.as_str(), data_key.version) {
-        ("cardinal", 1) => make_inv_response::<PluralRuleStringsV1>(),
-        ("ordinal", 1) => make_inv_response::<PluralRuleStringsV1>(),
+    match *data_key {
+        key::CARDINAL_V1 => make_inv_response::<PluralRuleStringsV1>(),
+        key::ORDINAL_V1 => make_inv_response::<PluralRuleStringsV1>(),
         _ => None,
     }
 }
@@ -23,18 +79,107 @@ pub(crate) fn get_invariant(data_key: &DataKey) -> Option<DataResponse<'static>>
 /// Plural rule strings conforming to UTS 35 syntax. Includes separate fields for five of the six
 /// standard plural forms. If none of the rules match, the "other" category is assumed.
 ///
+/// The strings may borrow from the data the struct was deserialized from for the lifetime `'s`,
+/// such as a memory-mapped data blob.
+///
 /// More information: https://unicode.org/reports/tr35/tr35-numbers.html#Language_Plural_Rules
 #[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
-pub struct PluralRuleStringsV1 {
-    pub zero: Option<Cow<'static, str>>,
-    pub one: Option<Cow<'static, str>>,
-    pub two: Option<Cow<'static, str>>,
-    pub few: Option<Cow<'static, str>>,
-    pub many: Option<Cow<'static, str>>,
+pub struct PluralRuleStringsV1<'s> {
+    pub zero: Option<Cow<'s, str>>,
+    pub one: Option<Cow<'s, str>>,
+    pub two: Option<Cow<'s, str>>,
+    pub few: Option<Cow<'s, str>>,
+    pub many: Option<Cow<'s, str>>,
+}
+
+/// Variant of [`PluralRuleStringsV1`] for deserializing with the rule strings borrowed from the
+/// data instead of copied. The derived Deserialize impl of PluralRuleStringsV1 copies them, so
+/// that it can be deserialized from any data.
+#[derive(Deserialize)]
+struct BorrowedPluralRuleStringsV1<'s> {
+    #[serde(borrow, deserialize_with = "super::deserialize_option_cow_str")]
+    zero: Option<Cow<'s, str>>,
+    #[serde(borrow, deserialize_with = "super::deserialize_option_cow_str")]
+    one: Option<Cow<'s, str>>,
+    #[serde(borrow, deserialize_with = "super::deserialize_option_cow_str")]
+    two: Option<Cow<'s, str>>,
+    #[serde(borrow, deserialize_with = "super::deserialize_option_cow_str")]
+    few: Option<Cow<'s, str>>,
+    #[serde(borrow, deserialize_with = "super::deserialize_option_cow_str")]
+    many: Option<Cow<'s, str>>,
+}
+
+impl<'s> From<BorrowedPluralRuleStringsV1<'s>> for PluralRuleStringsV1<'s> {
+    fn from(other: BorrowedPluralRuleStringsV1<'s>) -> Self {
+        Self {
+            zero: other.zero,
+            one: other.one,
+            two: other.two,
+            few: other.few,
+            many: other.many,
+        }
+    }
+}
+
+// Safety: Output is PluralRuleStringsV1 with the lifetime 'a, and to_owned_payload copies every
+// string.
+unsafe impl<'a> Yokeable<'a> for PluralRuleStringsV1<'static> {
+    type Output = PluralRuleStringsV1<'a>;
+
+    fn transform(&'a self) -> &'a Self::Output {
+        self
+    }
+
+    fn to_owned_payload(output: &Self::Output) -> Self {
+        let to_owned = |rule: &Option<Cow<str>>| rule.as_deref().map(|s| Cow::Owned(s.to_owned()));
+        Self {
+            zero: to_owned(&output.zero),
+            one: to_owned(&output.one),
+            two: to_owned(&output.two),
+            few: to_owned(&output.few),
+            many: to_owned(&output.many),
+        }
+    }
+}
+
+impl crate::bake::Bake for PluralRuleStringsV1<'static> {
+    const TYPE_PATH: &'static str = "::icu_data_provider::structs::plurals::PluralRuleStringsV1";
+    const IS_CONST: bool = true;
+
+    fn bake(&self) -> String {
+        use crate::bake::bake_option_cow_str;
+        format!(
+            "{} {{ zero: {}, one: {}, two: {}, few: {}, many: {} }}",
+            Self::TYPE_PATH,
+            bake_option_cow_str(&self.zero),
+            bake_option_cow_str(&self.one),
+            bake_option_cow_str(&self.two),
+            bake_option_cow_str(&self.few),
+            bake_option_cow_str(&self.many),
+        )
+    }
+}
+
+/// Marker type for the key `plurals/cardinal@1`, whose payload is [`PluralRuleStringsV1`].
+pub struct CardinalV1Marker;
+
+impl DataMarker for CardinalV1Marker {
+    type Yokeable = PluralRuleStringsV1<'static>;
+
+    const KEY: DataKey = key::CARDINAL_V1;
+}
+
+/// Marker type for the key `plurals/ordinal@1`, whose payload is [`PluralRuleStringsV1`].
+pub struct OrdinalV1Marker;
+
+impl DataMarker for OrdinalV1Marker {
+    type Yokeable = PluralRuleStringsV1<'static>;
+
+    const KEY: DataKey = key::ORDINAL_V1;
 }
 
 #[cfg(feature = "invariant")]
-impl Default for PluralRuleStringsV1 {
+impl Default for PluralRuleStringsV1<'static> {
     fn default() -> Self {
         Self {
             zero: None,
@@ -3,9 +3,12 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::prelude::v1::*;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::thread;
 
 use icu_data_provider::prelude::*;
 use icu_data_provider::structs;
+use icu_data_provider::AsyncAdapter;
 
 // This file tests DataProvider borrow semantics with a dummy data provider based on a JSON string.
 
@@ -141,3 +144,91 @@ fn test_clone_payload() {
     };
     check_data(&final_data);
 }
+
+#[test]
+fn test_load_typed() {
+    let warehouse = get_warehouse();
+    let response = warehouse
+        .provider()
+        .load_typed::<structs::decimal::SymbolsV1Marker>(&DataEntry {
+            variant: None,
+            langid: "en-US".parse().unwrap(),
+        })
+        .unwrap();
+    check_data(response.get());
+}
+
+#[test]
+fn test_load_typed_mismatch() {
+    // The dummy provider returns decimal symbols for all keys, so a typed request for plural
+    // rules must fail at runtime rather than returning the wrong type.
+    let warehouse = get_warehouse();
+    let result = warehouse
+        .provider()
+        .load_typed::<structs::plurals::CardinalV1Marker>(&DataEntry {
+            variant: None,
+            langid: "en-US".parse().unwrap(),
+        });
+    match result {
+        Err(DataError::MismatchedType { .. }) => (),
+        other => panic!("Expected MismatchedType, got: {:?}", other),
+    };
+}
+
+#[test]
+fn test_async_adapter() {
+    let warehouse = get_warehouse();
+    let provider = AsyncAdapter::new(warehouse.provider());
+    let response = futures::executor::block_on(provider.load_async(&DataRequest {
+        data_key: icu_data_key!(decimal: symbols@1),
+        data_entry: DataEntry {
+            variant: None,
+            langid: "en-US".parse().unwrap(),
+        },
+    }))
+    .unwrap();
+    let decimal_data: &structs::decimal::SymbolsV1 = response.borrow_payload().unwrap();
+    check_data(decimal_data);
+}
+
+#[test]
+fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<DataResponse>();
+    assert_send_sync::<DataError>();
+}
+
+#[test]
+fn test_take_shared_payload() {
+    let warehouse = get_warehouse();
+    let response = get_response(&warehouse);
+    let decimal_data: Arc<structs::decimal::SymbolsV1> = response.take_shared_payload().unwrap();
+    check_data(&decimal_data);
+}
+
+#[test]
+fn test_shared_payload_across_threads() {
+    let warehouse = get_warehouse();
+    let decimal_data: Arc<structs::decimal::SymbolsV1> =
+        get_response(&warehouse).take_shared_payload().unwrap();
+    let response = DataResponseBuilder {
+        data_langid: LanguageIdentifier::default(),
+    }
+    .with_shared_payload(decimal_data.clone());
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let response = response.clone();
+            thread::spawn(move || {
+                let shared: Arc<structs::decimal::SymbolsV1> =
+                    response.take_shared_payload().unwrap();
+                check_data(&shared);
+                shared
+            })
+        })
+        .collect();
+    for handle in handles {
+        // Every thread received the same allocation, not a copy.
+        assert!(Arc::ptr_eq(&decimal_data, &handle.join().unwrap()));
+    }
+}
@@ -0,0 +1,210 @@
+use crate::error::Error;
+use crate::manifest::ArchiveFormat;
+#[cfg(feature = "tar")]
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+#[cfg(any(feature = "zip", feature = "tar"))]
+use std::fs::File;
+#[cfg(any(feature = "zip", feature = "tar"))]
+use std::io::{BufReader, Read};
+use std::path::Path;
+#[cfg(any(feature = "zip", feature = "tar"))]
+use std::sync::Mutex;
+#[cfg(any(feature = "zip", feature = "tar"))]
+use std::sync::MutexGuard;
+
+/// An archive of a data directory, from which files are read on demand.
+///
+/// File names are paths relative to the data directory, with '/' as the separator. The names of
+/// the files and directories are indexed when the archive is opened.
+pub(crate) struct Archive {
+    reader: Reader,
+    /// The names of the files in the archive, sorted.
+    file_names: Vec<String>,
+    /// The names of the directories containing files, at any depth, without a trailing '/'.
+    dir_names: HashSet<String>,
+}
+
+enum Reader {
+    #[cfg(feature = "zip")]
+    Zip(Mutex<zip::ZipArchive<BufReader<File>>>),
+    #[cfg(feature = "tar")]
+    Tar {
+        file: Mutex<File>,
+        /// The position and size of the data of each file in the archive.
+        files: HashMap<String, (u64, u64)>,
+    },
+}
+
+// Without archive features, Reader has no variants, so the arguments are unused and the code
+// after matching on the format is unreachable.
+#[cfg_attr(
+    not(any(feature = "zip", feature = "tar")),
+    allow(unused_variables, unreachable_code)
+)]
+impl Archive {
+    /// Opens an archive, choosing the format from the file extension.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let format = match ArchiveFormat::from_path(path) {
+            Some(format) if format.is_enabled() => format,
+            format => return Err(Error::UnsupportedArchive(path.to_path_buf(), format)),
+        };
+        let reader: Reader = match format {
+            #[cfg(feature = "zip")]
+            ArchiveFormat::Zip => {
+                let reader = BufReader::new(File::open(path)?);
+                Reader::Zip(Mutex::new(zip::ZipArchive::new(reader)?))
+            }
+            #[cfg(feature = "tar")]
+            ArchiveFormat::Tar => open_tar(path)?,
+            #[allow(unreachable_patterns)]
+            _ => unreachable!("The archive format is enabled"),
+        };
+        let mut file_names = reader.file_names();
+        file_names.sort();
+        let mut dir_names = HashSet::new();
+        for name in file_names.iter() {
+            for (idx, _) in name.match_indices('/') {
+                dir_names.insert(name[..idx].to_string());
+            }
+        }
+        Ok(Self {
+            reader,
+            file_names,
+            dir_names,
+        })
+    }
+
+    /// Reads a file from the archive. Returns None if there is no such file.
+    pub fn read(&self, name: &str) -> Result<Option<Vec<u8>>, Error> {
+        match self.reader {
+            #[cfg(feature = "zip")]
+            Reader::Zip(ref zip_archive) => {
+                let mut zip_archive = lock(zip_archive);
+                let mut zip_file = match zip_archive.by_name(name) {
+                    Ok(zip_file) => zip_file,
+                    Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+                    Err(err) => return Err(err.into()),
+                };
+                // The size in the header is untrusted, so the buffer grows as the data is read.
+                let mut bytes = Vec::new();
+                zip_file.read_to_end(&mut bytes)?;
+                Ok(Some(bytes))
+            }
+            #[cfg(feature = "tar")]
+            Reader::Tar {
+                ref file,
+                ref files,
+            } => {
+                use std::io::{Seek, SeekFrom};
+                let (position, size) = match files.get(name) {
+                    Some(location) => *location,
+                    None => return Ok(None),
+                };
+                let mut file = lock(file);
+                file.seek(SeekFrom::Start(position))?;
+                // The size in the header is untrusted, so the buffer grows as the data is read.
+                let mut bytes = Vec::new();
+                (&mut *file).take(size).read_to_end(&mut bytes)?;
+                if (bytes.len() as u64) < size {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        format!("Truncated file in archive: {}", name),
+                    )
+                    .into());
+                }
+                Ok(Some(bytes))
+            }
+        }
+    }
+
+    /// Gets the names of the files in the archive, sorted.
+    pub fn file_names(&self) -> &[String] {
+        &self.file_names
+    }
+
+    /// Returns whether the archive has a directory with the given name, containing at least one
+    /// file.
+    pub fn dir_exists(&self, name: &str) -> bool {
+        self.dir_names.contains(name)
+    }
+}
+
+#[cfg_attr(not(any(feature = "zip", feature = "tar")), allow(unused_variables))]
+impl Reader {
+    /// Lists the names of the files in the archive, in no particular order.
+    fn file_names(&self) -> Vec<String> {
+        match *self {
+            #[cfg(feature = "zip")]
+            Reader::Zip(ref zip_archive) => lock(zip_archive)
+                .file_names()
+                .filter(|name| !name.ends_with('/'))
+                .map(String::from)
+                .collect(),
+            #[cfg(feature = "tar")]
+            Reader::Tar { ref files, .. } => files.keys().cloned().collect(),
+        }
+    }
+}
+
+impl fmt::Debug for Archive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Archive({} files)", self.file_names.len())
+    }
+}
+
+#[cfg(any(feature = "zip", feature = "tar"))]
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // Each read seeks before reading, so the reader is usable even if another thread panicked
+    // while holding the lock.
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Indexes the files in a tar archive. Hard links are resolved to the file they link to, so that
+/// a data directory exported with `AliasOption::HardLink` can be archived with `tar`.
+#[cfg(feature = "tar")]
+fn open_tar(path: &Path) -> Result<Reader, Error> {
+    let file = File::open(path)?;
+    let mut files = HashMap::new();
+    let mut links = vec![];
+    let mut tar_archive = tar::Archive::new(BufReader::new(&file));
+    for entry in tar_archive.entries()? {
+        let entry = entry?;
+        let name = get_tar_name(&entry.path()?);
+        match entry.header().entry_type() {
+            tar::EntryType::Regular => {
+                files.insert(name, (entry.raw_file_position(), entry.size()));
+            }
+            tar::EntryType::Link => {
+                if let Some(target) = entry.link_name()? {
+                    links.push((name, get_tar_name(&target)));
+                }
+            }
+            // Directories have no data, and symlinks are not supported.
+            _ => {}
+        }
+    }
+    for (name, target) in links {
+        if let Some(location) = files.get(&target).copied() {
+            files.insert(name, location);
+        }
+    }
+    Ok(Reader::Tar {
+        file: Mutex::new(file),
+        files,
+    })
+}
+
+/// Gets the name of a file in a tar archive, without a leading "./".
+#[cfg(feature = "tar")]
+fn get_tar_name(path: &Path) -> String {
+    let components: Vec<_> = path
+        .components()
+        .filter(|component| *component != std::path::Component::CurDir)
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect();
+    components.join("/")
+}
@@ -1,29 +1,44 @@
 use clap::{App, Arg, ArgGroup};
 use icu_cldr_json_data_provider::CldrJsonDataProvider;
 use icu_cldr_json_data_provider::CldrPaths;
-use icu_data_provider::icu_data_key;
+use icu_data_provider::iter::DataEntryCollection;
 use icu_data_provider::iter::IterableDataProvider;
+use icu_data_provider::prelude::*;
+use icu_data_provider::structs;
+use icu_data_provider::LocaleFilterProvider;
 use icu_fs_data_provider::export::fs_exporter;
+use icu_fs_data_provider::export::rust_exporter;
 use icu_fs_data_provider::export::serializers;
+use icu_fs_data_provider::export::ArchiveExporter;
+use icu_fs_data_provider::export::DryRunExporter;
 use icu_fs_data_provider::export::FilesystemExporter;
+use icu_fs_data_provider::export::RustExporter;
 use icu_fs_data_provider::manifest;
+use icu_locale::LanguageIdentifier;
 use std::ffi::OsStr;
 use std::fmt;
+use std::fs;
 use std::path::PathBuf;
 
 // #[derive(Debug)]
 enum Error {
-    Unsupported(&'static str),
     Export(icu_fs_data_provider::FsDataError),
     DataProvider(icu_data_provider::DataError),
+    Parse(icu_data_provider::ParseError),
+    LocaleParse(icu_locale::ParserError, String),
+    UnknownKey(DataKey),
+    Io(std::io::Error, PathBuf),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Error::Unsupported(message) => write!(f, "Unsupported: {}", message),
             Error::Export(error) => write!(f, "{}", error),
             Error::DataProvider(error) => write!(f, "{}", error),
+            Error::Parse(error) => write!(f, "{}", error),
+            Error::LocaleParse(error, s) => write!(f, "{}: {}", error, s),
+            Error::UnknownKey(data_key) => write!(f, "Unknown data key: {}", data_key),
+            Error::Io(error, path) => write!(f, "{}: {}", error, path.to_string_lossy()),
         }
     }
 }
@@ -46,6 +61,98 @@ impl From<icu_data_provider::DataError> for Error {
     }
 }
 
+impl From<icu_data_provider::ParseError> for Error {
+    fn from(err: icu_data_provider::ParseError) -> Error {
+        Error::Parse(err)
+    }
+}
+
+/// Parses a data key and checks that it is known to ICU4X.
+fn parse_key(s: &str) -> Result<DataKey, Error> {
+    let data_key: DataKey = s.parse()?;
+    if !structs::get_all_keys().contains(&data_key) {
+        return Err(Error::UnknownKey(data_key));
+    }
+    Ok(data_key)
+}
+
+/// Reads data keys from a text file, one per line. Empty lines and lines starting with '#' are
+/// ignored.
+fn read_key_file(path: PathBuf) -> Result<Vec<DataKey>, Error> {
+    let contents = fs::read_to_string(&path).map_err(|err| Error::Io(err, path))?;
+    contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_key)
+        .collect()
+}
+
+/// Parses a language identifier.
+fn parse_langid(s: &str) -> Result<LanguageIdentifier, Error> {
+    s.parse()
+        .map_err(|err| Error::LocaleParse(err, s.to_string()))
+}
+
+/// Reads language identifiers from a text file, one per line. Empty lines and lines starting
+/// with '#' are ignored.
+fn read_locale_file(path: PathBuf) -> Result<Vec<LanguageIdentifier>, Error> {
+    let contents = fs::read_to_string(&path).map_err(|err| Error::Io(err, path))?;
+    contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_langid)
+        .collect()
+}
+
+/// Gets the CLDR version of the source data by loading the first available entry of the keys.
+fn get_cldr_version(provider: &CldrJsonDataProvider, keys: &[DataKey]) -> Option<String> {
+    keys.iter().find_map(|data_key| {
+        let data_entry = provider.iter_for_key(data_key).ok()?.next()?;
+        let response = provider
+            .load(&DataRequest {
+                data_key: *data_key,
+                data_entry,
+            })
+            .ok()?;
+        response.metadata.data_version.map(|v| v.into_owned())
+    })
+}
+
+/// Returns whether the result of exporting a key should be ignored. With --all-keys, keys that
+/// are not supported by CLDR are skipped rather than treated as errors.
+fn is_skipped(
+    result: &Result<(), DataError>,
+    key: &DataKey,
+    all_keys: bool,
+    verbose: bool,
+) -> bool {
+    // Not every key known to ICU4X can be derived from CLDR.
+    let skipped = all_keys
+        && matches!(
+            result,
+            Err(DataError::UnsupportedCategory(_)) | Err(DataError::UnsupportedDataKey(_))
+        );
+    if skipped && verbose {
+        println!("Skipping key not supported by CLDR: {}", key);
+    }
+    skipped
+}
+
+/// Checks the result of exporting a key, skipping keys not supported by CLDR with --all-keys.
+fn check_export_result(
+    result: Result<(), DataError>,
+    key: &DataKey,
+    all_keys: bool,
+    verbose: bool,
+) -> Result<(), Error> {
+    if is_skipped(&result, key, all_keys, verbose) {
+        return Ok(());
+    }
+    Ok(result?)
+}
+
 fn main() -> Result<(), Error> {
     let matches = App::new("ICU4X Data Exporter")
         .version("0.0.1")
@@ -61,7 +168,22 @@ fn main() -> Result<(), Error> {
             Arg::with_name("DRY_RUN")
                 .short("n")
                 .long("dry-run")
-                .help("Do not touch the filesystem (consider using with -v)."),
+                // The report always includes the duplicates that aliasing would remove.
+                .conflicts_with("ALIASING")
+                .help(
+                    "Do not touch the filesystem. Instead, print a report of the number and \
+                    size of the entries for each key, as compressed with --compress, the \
+                    duplicates that aliasing would remove, and any errors.",
+                ),
+        )
+        .arg(
+            Arg::with_name("REPORT_FORMAT")
+                .long("report-format")
+                .takes_value(true)
+                .possible_value("text")
+                .possible_value("json")
+                .requires("DRY_RUN")
+                .help("Format of the --dry-run report. Defaults to text."),
         )
         .arg(
             Arg::with_name("ALIASING")
@@ -69,13 +191,40 @@ fn main() -> Result<(), Error> {
                 .takes_value(true)
                 .possible_value("none")
                 .possible_value("symlink")
-                .help("Sets the aliasing mode of the output on the filesystem."),
+                .possible_value("hardlink")
+                .possible_value("table")
+                .help(
+                    "Sets the aliasing mode of the output on the filesystem. \"table\" lists \
+                    duplicate data in the manifest, which works on any filesystem.",
+                ),
+        )
+        .arg(
+            Arg::with_name("COMPRESS")
+                .long("compress")
+                .takes_value(true)
+                .possible_value("gzip")
+                .possible_value("zstd")
+                .help("Compress the data files on the filesystem."),
         )
         .arg(
             Arg::with_name("OVERWRITE")
                 .short("W")
                 .long("overwrite")
-                .help("Delete the output directory before writing data."),
+                .help("Replace the output directory if it is not empty."),
+        )
+        .arg(
+            Arg::with_name("FORMAT")
+                .long("format")
+                .takes_value(true)
+                .possible_value("json")
+                .possible_value("rust")
+                .possible_value("zip")
+                .possible_value("tar")
+                .help(
+                    "Output format: a directory of JSON files, a Rust module (mod.rs) with \
+                    the data compiled in, or a zip or tar archive of the directory of JSON \
+                    files. Defaults to json. --compress and --aliasing do not apply to rust.",
+                ),
         )
         .arg(
             Arg::with_name("STYLE")
@@ -126,34 +275,77 @@ fn main() -> Result<(), Error> {
                 .arg("ALL_KEYS")
                 .required(true),
         )
+        .arg(
+            Arg::with_name("LOCALE")
+                .short("l")
+                .long("locales")
+                .multiple(true)
+                .takes_value(true)
+                .help(
+                    "Include only data for this locale in the output. Also see --locale-file. \
+                    If no locales are given, data for all locales is included.",
+                ),
+        )
+        .arg(
+            Arg::with_name("LOCALE_FILE")
+                .long("locale-file")
+                .takes_value(true)
+                .conflicts_with("LOCALE")
+                .help(
+                    "Path to text file with locales to include, one per line. Empty lines and \
+                    lines starting with '#' are ignored. Also see --locales.",
+                ),
+        )
+        .arg(
+            Arg::with_name("LOCALE_ANCESTORS")
+                .long("locale-ancestors")
+                .help(
+                    "Also include data for the fallback ancestors of the selected locales, \
+                    such as \"sr-Latn\", \"sr\", and \"und\" for \"sr-Latn-RS\".",
+                ),
+        )
         .arg(
             Arg::with_name("OUTPUT")
                 .short("o")
                 .long("out")
                 .help(
-                    "Path to output data directory. Must be empty or non-existent, unless \
-                    --overwrite is present, in which case the directory is deleted first.",
+                    "Path to output data directory, or archive file with --format zip or tar. \
+                    Must be empty or non-existent, unless --overwrite is present, in which case \
+                    it is replaced once the export succeeds.",
                 )
                 .takes_value(true)
-                .required(true),
+                .required_unless("DRY_RUN"),
         )
         .get_matches();
 
-    if !matches.is_present("ALL_KEYS") {
-        return Err(Error::Unsupported(
-            "Lists of keys are not yet supported (see #192)",
-        ));
-    }
-
-    if matches.is_present("DRY_RUN") {
-        return Err(Error::Unsupported("Dry-run is not yet supported"));
+    // clap cannot express a conflict with a single value of --format.
+    if matches.value_of("FORMAT") == Some("rust") {
+        for (name, flag) in &[("COMPRESS", "--compress"), ("ALIASING", "--aliasing")] {
+            if matches.is_present(name) {
+                clap::Error::with_description(
+                    &format!(
+                        "The argument '{}' cannot be used with '--format rust'",
+                        flag
+                    ),
+                    clap::ErrorKind::ArgumentConflict,
+                )
+                .exit();
+            }
+        }
     }
 
-    // TODO: Build up this list from --keys and --key-file
-    let keys = [
-        icu_data_key!(plurals: cardinal@1),
-        icu_data_key!(plurals: ordinal@1),
-    ];
+    let all_keys = matches.is_present("ALL_KEYS");
+    let keys: Vec<DataKey> = if all_keys {
+        structs::get_all_keys()
+    } else if let Some(path) = matches.value_of_os("KEY_FILE") {
+        read_key_file(PathBuf::from(path))?
+    } else {
+        matches
+            .values_of("KEY")
+            .expect("One of the KEYS arguments is required")
+            .map(parse_key)
+            .collect::<Result<Vec<DataKey>, Error>>()?
+    };
 
     let output_path = PathBuf::from(
         matches
@@ -167,7 +359,27 @@ fn main() -> Result<(), Error> {
         cldr_paths.cldr_core = Ok(path.into());
     }
 
-    let provider = CldrJsonDataProvider::new(&cldr_paths);
+    let locales: Option<Vec<LanguageIdentifier>> =
+        if let Some(path) = matches.value_of_os("LOCALE_FILE") {
+            Some(read_locale_file(PathBuf::from(path))?)
+        } else if let Some(values) = matches.values_of("LOCALE") {
+            Some(values.map(parse_langid).collect::<Result<_, Error>>()?)
+        } else {
+            None
+        };
+
+    let cldr_provider = CldrJsonDataProvider::new(&cldr_paths);
+
+    let verbose = matches.is_present("VERBOSE");
+    let data_version = get_cldr_version(&cldr_provider, &keys);
+
+    let provider: Box<dyn IterableDataProvider> = match locales {
+        Some(locales) if matches.is_present("LOCALE_ANCESTORS") => Box::new(
+            LocaleFilterProvider::try_new_with_ancestors(cldr_provider, locales)?,
+        ),
+        Some(locales) => Box::new(LocaleFilterProvider::new(cldr_provider, locales)),
+        None => Box::new(cldr_provider),
+    };
 
     let mut options = serializers::JsonSerializerOptions::default();
     if let Some(value) = matches.value_of("STYLE") {
@@ -179,27 +391,118 @@ fn main() -> Result<(), Error> {
     }
     let json_serializer = Box::new(serializers::JsonSerializer::new(&options));
 
+    let compression = match matches.value_of("COMPRESS") {
+        Some("gzip") => manifest::CompressionOption::Gzip,
+        Some("zstd") => manifest::CompressionOption::Zstd,
+        Some(_) => unreachable!(),
+        None => manifest::CompressionOption::None,
+    };
+
+    if matches.is_present("DRY_RUN") {
+        let mut exporter = DryRunExporter::try_new_with_compression(json_serializer, compression)?;
+        for key in keys.iter() {
+            // Record errors in the report rather than stopping the export.
+            let result = exporter.export_key(provider.as_ref(), key);
+            if !is_skipped(&result, key, all_keys, verbose) {
+                exporter.record_result(key, result);
+            }
+        }
+        let report = exporter.close();
+        match matches.value_of("REPORT_FORMAT") {
+            Some("json") => println!(
+                "{}",
+                serde_json::to_string_pretty(&report).expect("The report is serializable")
+            ),
+            _ => print!("{}", report),
+        }
+        return Ok(());
+    }
+
+    if matches.value_of("FORMAT") == Some("rust") {
+        if !matches.is_present("OVERWRITE")
+            && output_path.exists()
+            && fs::read_dir(&output_path)
+                .map_err(|err| Error::Io(err, output_path.clone()))?
+                .next()
+                .is_some()
+        {
+            return Err(Error::Io(
+                std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    "Output directory is not empty",
+                ),
+                output_path,
+            ));
+        }
+        fs::create_dir_all(&output_path).map_err(|err| Error::Io(err, output_path.clone()))?;
+        let module_path = output_path.join("mod.rs");
+        // Write to a hidden sibling first, so that a failed export leaves any existing mod.rs intact.
+        let partial_path = output_path.join(".mod.rs.partial");
+        if verbose {
+            println!("Writing: {}", module_path.to_string_lossy());
+        }
+        let file =
+            fs::File::create(&partial_path).map_err(|err| Error::Io(err, partial_path.clone()))?;
+        let mut options = rust_exporter::RustExporterOptions::default();
+        options.data_version = data_version;
+        let mut exporter = RustExporter::new(std::io::BufWriter::new(file), &options);
+        let result = keys.iter().try_for_each(|key| {
+            let result = provider.export_key(key, &mut exporter);
+            check_export_result(result, key, all_keys, verbose)
+        });
+        let result = result.and_then(|()| Ok(exporter.close()?));
+        if let Err(err) = result {
+            let _ = fs::remove_file(&partial_path);
+            return Err(err);
+        }
+        fs::rename(&partial_path, &module_path).map_err(|err| Error::Io(err, module_path))?;
+        return Ok(());
+    }
+
     let mut options = fs_exporter::ExporterOptions::default();
     options.root = output_path;
     if let Some(value) = matches.value_of("ALIASING") {
         options.aliasing = match value {
             "none" => manifest::AliasOption::NoAliases,
             "symlink" => manifest::AliasOption::Symlink,
+            "hardlink" => manifest::AliasOption::HardLink,
+            "table" => manifest::AliasOption::AliasTable,
             _ => unreachable!(),
         };
     }
+    options.compression = compression;
     if matches.is_present("OVERWRITE") {
         options.overwrite = fs_exporter::OverwriteOption::RemoveAndReplace
     }
-    options.verbose = matches.is_present("VERBOSE");
+    options.verbose = verbose;
+    options.data_version = data_version;
+
+    let archive_format = match matches.value_of("FORMAT") {
+        Some("zip") => Some(manifest::ArchiveFormat::Zip),
+        Some("tar") => Some(manifest::ArchiveFormat::Tar),
+        _ => None,
+    };
+    if let Some(archive_format) = archive_format {
+        let mut exporter = ArchiveExporter::try_new(json_serializer, archive_format, &options)?;
+        for key in keys.iter() {
+            let result = provider.export_key(key, &mut exporter);
+            check_export_result(result, key, all_keys, verbose)?;
+            exporter.flush()?;
+        }
+        exporter.commit()?;
+        return Ok(());
+    }
+
     let mut exporter = FilesystemExporter::try_new(json_serializer, &options)?;
 
     for key in keys.iter() {
         let result = provider.export_key(key, &mut exporter);
-        // Ensure flush() is called, even when the result is an error
+        check_export_result(result, key, all_keys, verbose)?;
         exporter.flush()?;
-        result?;
     }
+    // If the export failed, the exporter is dropped without committing, leaving the output
+    // directory untouched.
+    exporter.commit()?;
 
     Ok(())
 }
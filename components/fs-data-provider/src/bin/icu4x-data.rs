@@ -0,0 +1,91 @@
+use clap::{App, AppSettings, Arg, SubCommand};
+use icu_fs_data_provider::verify;
+use icu_fs_data_provider::FsDataProvider;
+use std::fmt;
+use std::path::PathBuf;
+
+enum Error {
+    Load(icu_fs_data_provider::FsDataError, PathBuf),
+    Verify(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Load(error, path) => write!(f, "{}: {}", error, path.to_string_lossy()),
+            Error::Verify(count) => write!(f, "Verification found {} problem(s)", count),
+        }
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self as &dyn fmt::Display).fmt(f)
+    }
+}
+
+/// Verifies a data directory or an archive of one, printing every problem found.
+fn run_verify(root: PathBuf, verbose: bool) -> Result<(), Error> {
+    let result = if root.is_file() {
+        FsDataProvider::try_new_archive(root.clone())
+    } else {
+        FsDataProvider::try_new(root.clone())
+    };
+    let provider = result.map_err(|err| Error::Load(err, root))?;
+    let report = verify::verify(&provider);
+    for problem in report.problems.iter() {
+        println!("{}", problem);
+    }
+    if verbose {
+        println!(
+            "Checked {} entries and {} checksums",
+            report.entries_checked, report.checksums_checked
+        );
+    }
+    if !report.problems.is_empty() {
+        return Err(Error::Verify(report.problems.len()));
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Error> {
+    let matches = App::new("ICU4X Data Tool")
+        .version("0.0.1")
+        .author("The ICU4X Project Developers")
+        .about("Inspect ICU4X data directories")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("verify")
+                .about(
+                    "Check that every entry in a data directory can be loaded, matches the \
+                    checksum in the manifest, and contains valid plural rules.",
+                )
+                .arg(
+                    Arg::with_name("VERBOSE")
+                        .short("v")
+                        .long("verbose")
+                        .help("Print a summary of the checks."),
+                )
+                .arg(
+                    Arg::with_name("DIRECTORY")
+                        .help(
+                            "Path to the data directory containing manifest.json, or to a zip \
+                            or tar archive of it.",
+                        )
+                        .required(true),
+                ),
+        )
+        .get_matches();
+
+    match matches.subcommand() {
+        ("verify", Some(matches)) => run_verify(
+            PathBuf::from(
+                matches
+                    .value_of_os("DIRECTORY")
+                    .expect("DIRECTORY is required"),
+            ),
+            matches.is_present("VERBOSE"),
+        ),
+        _ => unreachable!("A subcommand is required"),
+    }
+}
@@ -1,11 +1,33 @@
+use crate::manifest::ArchiveFormat;
+use crate::manifest::CompressionOption;
+use crate::manifest::SyntaxOption;
+use crate::manifest::MANIFEST_FILE;
 use std::fmt;
+use std::path::PathBuf;
 
 #[derive(Debug)]
 pub enum Error {
     DataProviderError(icu_data_provider::DataError),
+    /// A file name in the data directory is not a valid DataEntry.
+    ParseError(icu_data_provider::ParseError),
     SerdeJsonError(serde_json::error::Error),
     #[cfg(feature = "export")]
     SerializerError(erased_serde::Error),
+    #[cfg(feature = "bincode")]
+    BincodeError(bincode::Error),
+    #[cfg(feature = "postcard")]
+    PostcardError(postcard::Error),
+    /// The manifest specifies a syntax whose feature is not enabled.
+    UnsupportedSyntax(SyntaxOption),
+    /// The manifest specifies a compression whose feature is not enabled.
+    UnsupportedCompression(CompressionOption),
+    /// The path does not have the file extension of an archive format, or the feature for the
+    /// archive format is not enabled.
+    UnsupportedArchive(PathBuf, Option<ArchiveFormat>),
+    /// The archive does not contain a manifest.
+    MissingManifest(PathBuf),
+    #[cfg(feature = "zip")]
+    ZipError(zip::result::ZipError),
     // TODO: Consider adding the path to IoError
     IoError(std::io::Error),
 }
@@ -16,6 +38,12 @@ impl From<icu_data_provider::DataError> for Error {
     }
 }
 
+impl From<icu_data_provider::ParseError> for Error {
+    fn from(err: icu_data_provider::ParseError) -> Error {
+        Error::ParseError(err)
+    }
+}
+
 impl From<serde_json::error::Error> for Error {
     fn from(err: serde_json::error::Error) -> Error {
         Error::SerdeJsonError(err)
@@ -29,6 +57,27 @@ impl From<erased_serde::Error> for Error {
     }
 }
 
+#[cfg(feature = "zip")]
+impl From<zip::result::ZipError> for Error {
+    fn from(err: zip::result::ZipError) -> Error {
+        Error::ZipError(err)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl From<bincode::Error> for Error {
+    fn from(err: bincode::Error) -> Error {
+        Error::BincodeError(err)
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl From<postcard::Error> for Error {
+    fn from(err: postcard::Error) -> Error {
+        Error::PostcardError(err)
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Error {
         Error::IoError(err)
@@ -39,9 +88,43 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Error::DataProviderError(error) => write!(f, "{}", error),
+            Error::ParseError(error) => write!(f, "{}", error),
             Error::SerdeJsonError(error) => write!(f, "{}", error),
             #[cfg(feature = "export")]
             Error::SerializerError(error) => write!(f, "{}", error),
+            #[cfg(feature = "bincode")]
+            Error::BincodeError(error) => write!(f, "{}", error),
+            #[cfg(feature = "postcard")]
+            Error::PostcardError(error) => write!(f, "{}", error),
+            Error::UnsupportedSyntax(syntax) => write!(
+                f,
+                "Syntax {:?} is not supported; enable the corresponding feature",
+                syntax
+            ),
+            Error::UnsupportedCompression(compression) => write!(
+                f,
+                "Compression {:?} is not supported; enable the corresponding feature",
+                compression
+            ),
+            Error::UnsupportedArchive(path, Some(format)) => write!(
+                f,
+                "Archive format {:?} of {} is not supported; enable the corresponding feature",
+                format,
+                path.to_string_lossy()
+            ),
+            Error::UnsupportedArchive(path, None) => write!(
+                f,
+                "Unknown archive format: {}; expected a .zip or .tar file",
+                path.to_string_lossy()
+            ),
+            Error::MissingManifest(path) => write!(
+                f,
+                "{} not found in archive: {}",
+                MANIFEST_FILE,
+                path.to_string_lossy()
+            ),
+            #[cfg(feature = "zip")]
+            Error::ZipError(error) => write!(f, "{}", error),
             Error::IoError(error) => write!(f, "{}", error),
         }
     }
@@ -51,9 +134,20 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::DataProviderError(error) => Some(error),
+            Error::ParseError(error) => Some(error),
             Error::SerdeJsonError(error) => Some(error),
             #[cfg(feature = "export")]
             Error::SerializerError(error) => Some(error),
+            #[cfg(feature = "bincode")]
+            Error::BincodeError(error) => Some(error),
+            #[cfg(feature = "postcard")]
+            Error::PostcardError(error) => Some(error),
+            Error::UnsupportedSyntax(_) => None,
+            Error::UnsupportedCompression(_) => None,
+            Error::UnsupportedArchive(..) => None,
+            Error::MissingManifest(_) => None,
+            #[cfg(feature = "zip")]
+            Error::ZipError(error) => Some(error),
             Error::IoError(error) => Some(error),
         }
     }
@@ -24,7 +24,6 @@ pub(crate) struct AliasCollection<T> {
     data_file_prefix: String,
     data_file_extension: String,
     map: HashMap<T, Vec<PathBuf>>,
-    flushed: bool,
 }
 
 impl<T: fmt::Debug + Eq + Hash + Ord + AsRef<[u8]>> AliasCollection<T> {
@@ -35,7 +34,6 @@ impl<T: fmt::Debug + Eq + Hash + Ord + AsRef<[u8]>> AliasCollection<T> {
             data_file_prefix: options.data_file_prefix.to_owned(),
             data_file_extension: options.data_file_extension.to_owned(),
             map: HashMap::new(),
-            flushed: false,
         }
     }
 
@@ -48,7 +46,6 @@ impl<T: fmt::Debug + Eq + Hash + Ord + AsRef<[u8]>> AliasCollection<T> {
     }
 
     pub fn flush(&mut self) -> Result<(), std::io::Error> {
-        self.flushed = true;
         // TODO: Make sure the directory is empty
         fs::create_dir_all(&self.root)?;
         let mut unique_data_items: Vec<(&T, &Vec<PathBuf>)> = self.map.iter().collect();
@@ -67,11 +64,3 @@ impl<T: fmt::Debug + Eq + Hash + Ord + AsRef<[u8]>> AliasCollection<T> {
         Ok(())
     }
 }
-
-impl<T> Drop for AliasCollection<T> {
-    fn drop(&mut self) {
-        if !self.flushed {
-            panic!("Please call flush before dropping AliasCollection");
-        }
-    }
-}
@@ -0,0 +1,214 @@
+use super::fs_exporter::{get_sibling_path, ExporterOptions, FilesystemExporter, OverwriteOption};
+use super::serializers::Serializer;
+use crate::error::Error;
+use crate::manifest::ArchiveFormat;
+use icu_data_provider::iter::DataExporter;
+use icu_data_provider::prelude::*;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A data exporter that writes a data directory into a zip or tar archive, to be read by
+/// [`FsDataProvider::try_new_archive`](crate::FsDataProvider::try_new_archive).
+///
+/// The data is first written to a temporary directory next to the archive with a
+/// [`FilesystemExporter`], and packed into the archive by
+/// [`commit`](ArchiveExporter::commit). Files are added to the archive in sorted order without
+/// timestamps, so exporting the same data produces the same archive.
+///
+/// Symlinks and hard links are replaced by copies of the files they link to, so use
+/// `AliasOption::AliasTable` to de-duplicate data in archives.
+///
+/// # Examples
+///
+/// This example requires the `tar` feature.
+///
+/// ```no_run
+/// use icu_data_provider::iter::IterableDataProvider;
+/// use icu_data_provider::structs::plurals;
+/// use icu_data_provider::InvariantDataProvider;
+/// use icu_fs_data_provider::export::{fs_exporter, serializers, ArchiveExporter};
+/// use icu_fs_data_provider::manifest::ArchiveFormat;
+/// use icu_fs_data_provider::FsDataProvider;
+///
+/// let demo_path = std::env::temp_dir().join("icu4x_archive_demo.tar");
+///
+/// let json_serializer = Box::new(serializers::JsonSerializer::new(
+///     &serializers::JsonSerializerOptions::default(),
+/// ));
+/// let mut options = fs_exporter::ExporterOptions::default();
+/// options.root = demo_path.clone();
+/// options.overwrite = fs_exporter::OverwriteOption::RemoveAndReplace;
+/// let mut exporter = ArchiveExporter::try_new(json_serializer, ArchiveFormat::Tar, &options)
+///     .expect("Should successfully initialize the archive exporter");
+/// InvariantDataProvider
+///     .export_key(&plurals::key::CARDINAL_V1, &mut exporter)
+///     .expect("Should successfully export");
+/// exporter.commit().expect("Should successfully write the archive");
+///
+/// let provider = FsDataProvider::try_new_archive(demo_path.clone())
+///     .expect("Should successfully read the archive");
+/// assert_eq!(
+///     provider.supported_keys().unwrap(),
+///     vec![plurals::key::CARDINAL_V1]
+/// );
+///
+/// std::fs::remove_file(&demo_path).expect("Should clean up test archive");
+/// ```
+pub struct ArchiveExporter {
+    inner: FilesystemExporter,
+    format: ArchiveFormat,
+    /// The directory written by the inner FilesystemExporter.
+    contents_root: PathBuf,
+    /// The archive file, which is replaced on commit.
+    output_path: PathBuf,
+}
+
+impl DataExporter for ArchiveExporter {
+    fn put(
+        &mut self,
+        req: &DataRequest,
+        obj: &dyn erased_serde::Serialize,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.inner.put(req, obj)
+    }
+}
+
+impl ArchiveExporter {
+    /// Creates an ArchiveExporter writing an archive of the given format to `options.root`. The
+    /// archive format must be enabled by its feature. With `OverwriteOption::CheckEmpty`, the
+    /// archive must not already exist.
+    pub fn try_new(
+        serializer: Box<dyn Serializer>,
+        format: ArchiveFormat,
+        options: &ExporterOptions,
+    ) -> Result<Self, Error> {
+        if !format.is_enabled() {
+            return Err(Error::UnsupportedArchive(
+                options.root.to_path_buf(),
+                Some(format),
+            ));
+        }
+        if options.overwrite == OverwriteOption::CheckEmpty && options.root.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "Output archive already exists: {}",
+                    options.root.to_string_lossy()
+                ),
+            )
+            .into());
+        }
+        let contents_root = get_sibling_path(&options.root, "contents")?;
+        let mut inner_options = options.clone();
+        inner_options.root = contents_root.clone();
+        // The directory may be left over from an export that crashed.
+        inner_options.overwrite = OverwriteOption::RemoveAndReplace;
+        Ok(Self {
+            inner: FilesystemExporter::try_new(serializer, &inner_options)?,
+            format,
+            contents_root,
+            output_path: options.root.to_path_buf(),
+        })
+    }
+
+    /// See [`FilesystemExporter::flush`].
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.inner.flush()
+    }
+
+    /// Flushes the exporter and writes the archive, replacing the existing archive, if any.
+    /// Consumes the ArchiveExporter.
+    ///
+    /// The archive is written to a temporary file and renamed into place, so the archive is
+    /// either the old one or the complete new one.
+    pub fn commit(self) -> Result<(), Error> {
+        let ArchiveExporter {
+            inner,
+            format,
+            contents_root,
+            output_path,
+        } = self;
+        inner.commit()?;
+        let partial_path = get_sibling_path(&output_path, "partial")?;
+        let result = write_archive(&contents_root, &partial_path, format).and_then(|()| {
+            fs::rename(&partial_path, &output_path)?;
+            Ok(())
+        });
+        if result.is_err() {
+            let _ = fs::remove_file(&partial_path);
+        }
+        fs::remove_dir_all(&contents_root)?;
+        result
+    }
+}
+
+/// Writes the files in a directory to an archive, with paths relative to the directory.
+// Without archive features, no format is enabled, so the files are unused.
+#[cfg_attr(not(any(feature = "zip", feature = "tar")), allow(unused_variables))]
+fn write_archive(dir: &Path, archive_path: &Path, format: ArchiveFormat) -> Result<(), Error> {
+    let mut files = vec![];
+    collect_files(dir, dir, &mut files)?;
+    files.sort();
+    let archive_file = fs::File::create(archive_path)?;
+    match format {
+        #[cfg(feature = "zip")]
+        ArchiveFormat::Zip => {
+            use std::io::Write;
+            let mut zip_writer = zip::ZipWriter::new(io::BufWriter::new(archive_file));
+            // Without the `time` feature of zip, files have a fixed modification time.
+            let options = zip::write::FileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated);
+            for (name, path) in files {
+                zip_writer.start_file(name, options)?;
+                zip_writer.write_all(&fs::read(&path)?)?;
+            }
+            zip_writer
+                .finish()?
+                .into_inner()
+                .map_err(|err| err.into_error())?;
+            Ok(())
+        }
+        #[cfg(feature = "tar")]
+        ArchiveFormat::Tar => {
+            let mut builder = tar::Builder::new(io::BufWriter::new(archive_file));
+            for (name, path) in files {
+                let bytes = fs::read(&path)?;
+                let mut header = tar::Header::new_gnu();
+                header.set_size(bytes.len() as u64);
+                header.set_mode(0o644);
+                header.set_mtime(0);
+                header.set_cksum();
+                builder.append_data(&mut header, name, bytes.as_slice())?;
+            }
+            builder
+                .into_inner()?
+                .into_inner()
+                .map_err(|err| err.into_error())?;
+            Ok(())
+        }
+        #[allow(unreachable_patterns)]
+        _ => unreachable!("The archive format was checked in try_new"),
+    }
+}
+
+/// Adds the files in a directory and its subdirectories to the list, along with their paths
+/// relative to the root directory with '/' as the separator. Symlinks are followed.
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<(String, PathBuf)>) -> io::Result<()> {
+    for dir_entry in fs::read_dir(dir)? {
+        let path = dir_entry?.path();
+        if path.is_dir() {
+            collect_files(root, &path, files)?;
+            continue;
+        }
+        let relative_path = path
+            .strip_prefix(root)
+            .expect("The file is in the root directory");
+        let components: Vec<_> = relative_path
+            .iter()
+            .map(|component| component.to_string_lossy())
+            .collect();
+        files.push((components.join("/"), path));
+    }
+    Ok(())
+}
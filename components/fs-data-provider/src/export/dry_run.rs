@@ -0,0 +1,214 @@
+use super::fs_exporter::compress;
+use super::serializers::Serializer;
+use crate::error::Error;
+use crate::manifest;
+use crate::manifest::CompressionOption;
+use icu_data_provider::iter::DataEntryCollection;
+use icu_data_provider::iter::DataExporter;
+use icu_data_provider::prelude::*;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+/// Statistics about the data exported for a single DataKey.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct KeyReport {
+    /// Number of DataEntries exported.
+    pub entries: usize,
+    /// Total size of the serialized payloads, in bytes.
+    pub bytes: u64,
+    /// Number of payloads identical to another payload in the same directory, which aliasing
+    /// would replace with an alias.
+    pub duplicates: usize,
+    /// Total size of the duplicate payloads, in bytes, which aliasing would save.
+    pub duplicate_bytes: u64,
+    /// The errors of the DataKey, such as a DataRequest that failed to load, in order. The
+    /// export continues with the remaining entries after an error in a single entry.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+/// Statistics about the data passed to a DryRunExporter, by DataKey.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ExportReport {
+    /// The statistics for each DataKey, in string form.
+    pub keys: BTreeMap<String, KeyReport>,
+}
+
+impl ExportReport {
+    /// Gets the sum of the statistics for all DataKeys. The errors are not included.
+    pub fn total(&self) -> KeyReport {
+        let mut total = KeyReport::default();
+        for report in self.keys.values() {
+            total.entries += report.entries;
+            total.bytes += report.bytes;
+            total.duplicates += report.duplicates;
+            total.duplicate_bytes += report.duplicate_bytes;
+        }
+        total
+    }
+}
+
+impl fmt::Display for ExportReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (key, report) in self.keys.iter() {
+            write_report_line(f, key, report)?;
+        }
+        write_report_line(f, "Total", &self.total())
+    }
+}
+
+/// Writes the statistics for a DataKey as a line of text.
+fn write_report_line(f: &mut fmt::Formatter, key: &str, report: &KeyReport) -> fmt::Result {
+    write!(
+        f,
+        "{}: {} entries, {} bytes, {} duplicates ({} bytes)",
+        key, report.entries, report.bytes, report.duplicates, report.duplicate_bytes
+    )?;
+    for error in report.errors.iter() {
+        write!(f, ", error: {}", error)?;
+    }
+    writeln!(f)
+}
+
+/// A data exporter that serializes data without writing it, and reports statistics about it.
+///
+/// This can be used to preview an export, such as with `icu4x-cldr-export --dry-run`. The
+/// payloads are serialized with the given serializer and compressed like the data files of a
+/// FilesystemExporter to measure their size, and payloads that are identical to another payload
+/// in the same directory are counted as duplicates.
+///
+/// Use [`DryRunExporter::export_key`] to report every entry that fails to load, rather than
+/// stopping at the first one like [`IterableDataProvider::export_key`].
+///
+/// [`IterableDataProvider::export_key`]: icu_data_provider::iter::IterableDataProvider::export_key
+///
+/// # Examples
+///
+/// ```
+/// use icu_data_provider::structs::plurals;
+/// use icu_data_provider::InvariantDataProvider;
+/// use icu_fs_data_provider::export::serializers;
+/// use icu_fs_data_provider::export::DryRunExporter;
+///
+/// let json_serializer = Box::new(serializers::JsonSerializer::new(
+///     &serializers::JsonSerializerOptions::default(),
+/// ));
+/// let mut exporter = DryRunExporter::new(json_serializer);
+/// let result = exporter.export_key(&InvariantDataProvider, &plurals::key::CARDINAL_V1);
+/// exporter.record_result(&plurals::key::CARDINAL_V1, result);
+///
+/// let report = exporter.close();
+/// assert_eq!(report.keys["plurals/cardinal@1"].entries, 1);
+/// ```
+pub struct DryRunExporter {
+    serializer: Box<dyn Serializer>,
+    compression: CompressionOption,
+    report: ExportReport,
+    /// Hashes of the payloads seen so far, by DataKey and variant.
+    seen: HashSet<(String, Option<String>, String)>,
+}
+
+impl DataExporter for DryRunExporter {
+    fn put(
+        &mut self,
+        req: &DataRequest,
+        obj: &dyn erased_serde::Serialize,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut buf: Vec<u8> = Vec::new();
+        self.serializer.serialize(obj, &mut buf)?;
+        let buf = compress(self.compression, buf)?;
+        let data_key = req.data_key.to_string();
+        // Aliasing de-duplicates the files in the directory of each DataKey and variant.
+        let is_duplicate = !self.seen.insert((
+            data_key.clone(),
+            req.data_entry.variant.as_ref().map(|v| v.to_string()),
+            manifest::get_hash(&buf),
+        ));
+        let report = self.report.keys.entry(data_key).or_default();
+        report.entries += 1;
+        report.bytes += buf.len() as u64;
+        if is_duplicate {
+            report.duplicates += 1;
+            report.duplicate_bytes += buf.len() as u64;
+        }
+        Ok(())
+    }
+}
+
+impl DryRunExporter {
+    pub fn new(serializer: Box<dyn Serializer>) -> Self {
+        Self {
+            serializer,
+            compression: CompressionOption::None,
+            report: ExportReport::default(),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Creates a DryRunExporter that measures the payloads after compressing them. Returns
+    /// UnsupportedCompression if the feature for the compression is not enabled.
+    pub fn try_new_with_compression(
+        serializer: Box<dyn Serializer>,
+        compression: CompressionOption,
+    ) -> Result<Self, Error> {
+        if !compression.is_enabled() {
+            return Err(Error::UnsupportedCompression(compression));
+        }
+        Ok(Self {
+            compression,
+            ..Self::new(serializer)
+        })
+    }
+
+    /// Exports all data in the provider for the specified key into this exporter. Unlike
+    /// [`IterableDataProvider::export_key`], an entry that fails to load or serialize does not
+    /// stop the export: the error is recorded in the report along with the DataRequest, and the
+    /// remaining entries are still counted.
+    ///
+    /// Returns an Error only if the entries of the DataKey cannot be listed, such as for a
+    /// DataKey that the provider does not support. Pass it to [`DryRunExporter::record_result`]
+    /// to record it in the report.
+    ///
+    /// [`IterableDataProvider::export_key`]: icu_data_provider::iter::IterableDataProvider::export_key
+    pub fn export_key<'d, P>(&mut self, provider: &P, data_key: &DataKey) -> Result<(), DataError>
+    where
+        P: DataProvider<'d> + DataEntryCollection + ?Sized,
+    {
+        for data_entry in provider.iter_for_key(data_key)? {
+            let req = DataRequest {
+                data_key: *data_key,
+                data_entry,
+            };
+            let result = provider.load(&req).and_then(|response| {
+                let payload = response.borrow_as_serialize();
+                Ok(self.put(&req, &payload)?)
+            });
+            if let Err(err) = result {
+                self.report
+                    .keys
+                    .entry(data_key.to_string())
+                    .or_default()
+                    .errors
+                    .push(format!("{}: {}", req, err));
+            }
+        }
+        Ok(())
+    }
+
+    /// Records the result of exporting a DataKey, so that errors appear in the report. Also
+    /// ensures that the DataKey appears in the report if it has no entries.
+    pub fn record_result(&mut self, data_key: &DataKey, result: Result<(), DataError>) {
+        let report = self.report.keys.entry(data_key.to_string()).or_default();
+        if let Err(err) = result {
+            report.errors.push(err.to_string());
+        }
+    }
+
+    /// Returns the report for all data passed to this exporter.
+    pub fn close(self) -> ExportReport {
+        self.report
+    }
+}
@@ -1,25 +1,31 @@
 use super::aliasing::{self, AliasCollection};
 use super::serializers::Serializer;
 use crate::error::Error;
+use crate::manifest;
 use crate::manifest::AliasOption;
+use crate::manifest::CompressionOption;
 use crate::manifest::Manifest;
 use crate::manifest::SyntaxOption;
 use crate::manifest::MANIFEST_FILE;
+use crate::manifest::SYMLINK_FILE_EXTENSION;
 use icu_data_provider::iter::DataExporter;
 use icu_data_provider::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::io;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum OverwriteOption {
     /// If the directory doesn't exist, create it.
-    /// If it does exist, remove it safely (rmdir) and re-create it.
+    /// If it does exist, it must be empty; it is removed safely (rmdir) and replaced on commit.
     CheckEmpty,
     /// If the directory doesn't exist, create it.
-    /// If it does exist, remove it aggressively (rm -rf) and re-create it.
+    /// If it does exist, it is removed aggressively (rm -rf) and replaced on commit.
     RemoveAndReplace,
 }
 
@@ -31,10 +37,14 @@ pub struct ExporterOptions {
     pub root: PathBuf,
     /// Strategy for de-duplicating locale data.
     pub aliasing: AliasOption,
+    /// Compression of the data files.
+    pub compression: CompressionOption,
     /// Option for initializing the output directory.
     pub overwrite: OverwriteOption,
     /// Whether to print progress to stdout.
     pub verbose: bool,
+    /// Version of the exported data, such as the CLDR version, to record in the manifest.
+    pub data_version: Option<String>,
 }
 
 impl Default for ExporterOptions {
@@ -42,26 +52,46 @@ impl Default for ExporterOptions {
         Self {
             root: PathBuf::from("icu4x_data"),
             aliasing: AliasOption::NoAliases,
+            compression: CompressionOption::None,
             overwrite: OverwriteOption::CheckEmpty,
             verbose: false,
+            data_version: None,
         }
     }
 }
 
 /// A data exporter that writes data to a filesystem hierarchy.
 /// See the module-level docs for an example.
+///
+/// The data is written to a temporary directory next to the output directory, which is renamed
+/// into place by [`commit`](FilesystemExporter::commit). Until then, the output directory is left
+/// untouched, so readers never observe partially exported data. If the FilesystemExporter is
+/// dropped without being committed, for example because the export failed, the temporary
+/// directory is removed.
+///
+/// Only one FilesystemExporter may write to a given output directory at a time.
 pub struct FilesystemExporter {
+    /// The temporary directory being written.
     root: PathBuf,
+    /// The output directory, which is replaced by the temporary directory on commit.
+    output_root: PathBuf,
+    overwrite: OverwriteOption,
     manifest: Manifest,
     alias_collection: Option<AliasCollection<Vec<u8>>>,
+    /// The data files written so far with `AliasOption::HardLink` and `AliasOption::AliasTable`,
+    /// by directory and hash, along with their DataEntry in string form. Cleared on flush.
+    written_files: HashMap<(PathBuf, String), (PathBuf, String)>,
     verbose: bool,
     serializer: Box<dyn Serializer>,
+    committed: bool,
 }
 
 impl Drop for FilesystemExporter {
     fn drop(&mut self) {
-        if self.alias_collection.is_some() {
-            panic!("Please call flush before dropping FilesystemExporter");
+        if !self.committed {
+            // Errors cannot be reported from drop; a leftover directory is removed by the next
+            // export to the same output directory.
+            let _ = fs::remove_dir_all(&self.root);
         }
     }
 }
@@ -71,14 +101,26 @@ impl DataExporter for FilesystemExporter {
         &mut self,
         req: &DataRequest,
         obj: &dyn erased_serde::Serialize,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         let mut path_buf = self.root.clone();
         path_buf.extend(req.data_key.get_components().iter());
         path_buf.extend(req.data_entry.get_components().iter());
         if self.verbose {
             println!("Initializing: {}", path_buf.to_string_lossy());
         }
-        self.write_to_path(path_buf, obj)
+        let mut buf: Vec<u8> = Vec::new();
+        self.serializer.serialize(obj, &mut buf)?;
+        let buf = compress(self.manifest.compression, buf)?;
+        // The hash is of the data file, so that it can be checked without decompressing it.
+        let hash = manifest::get_hash(&buf);
+        // The inventory is written to the manifest on flush.
+        self.manifest
+            .keys
+            .get_or_insert_with(BTreeMap::new)
+            .entry(req.data_key.to_string())
+            .or_default()
+            .insert(req.data_entry.to_string(), hash.clone());
+        self.write_to_path(req, path_buf, buf, hash)
     }
 }
 
@@ -87,82 +129,229 @@ impl FilesystemExporter {
         serializer: Box<dyn Serializer>,
         options: &ExporterOptions,
     ) -> Result<Self, Error> {
+        if !options.compression.is_enabled() {
+            return Err(Error::UnsupportedCompression(options.compression));
+        }
+        check_output_root(&options.root, options.overwrite)?;
+        let root = get_sibling_path(&options.root, "partial")?;
+        // The directory may be left over from an export that crashed.
+        if root.exists() {
+            fs::remove_dir_all(&root)?;
+        }
+        fs::create_dir_all(&root)?;
         let result = FilesystemExporter {
-            root: options.root.to_path_buf(),
+            root,
+            output_root: options.root.to_path_buf(),
+            overwrite: options.overwrite,
             manifest: Manifest {
                 aliasing: options.aliasing,
-                syntax: SyntaxOption::Json,
+                syntax: SyntaxOption::clone(&serializer),
+                compression: options.compression,
+                data_version: options.data_version.clone(),
+                keys: None,
+                aliases: None,
             },
             alias_collection: None,
+            written_files: HashMap::new(),
             verbose: options.verbose,
             serializer,
+            committed: false,
         };
+        Ok(result)
+    }
 
-        match options.overwrite {
-            OverwriteOption::CheckEmpty => {
-                if options.root.exists() {
-                    fs::remove_dir(&options.root)?;
-                }
-            }
-            OverwriteOption::RemoveAndReplace => {
-                if options.root.exists() {
-                    fs::remove_dir_all(&options.root)?;
-                }
-            }
-        };
-        fs::create_dir_all(&options.root)?;
-
-        let mut manifest_path = result.root.to_path_buf();
+    /// Writes the manifest, including the inventory of the data exported so far.
+    fn write_manifest(&self) -> Result<(), Error> {
+        let mut manifest_path = self.root.to_path_buf();
         manifest_path.push(MANIFEST_FILE);
         let mut manifest_file = fs::File::create(manifest_path)?;
         let mut manifest_writer = serde_json::Serializer::pretty(&mut manifest_file);
-        result.manifest.serialize(&mut manifest_writer)?;
+        self.manifest.serialize(&mut manifest_writer)?;
         writeln!(&mut manifest_file)?;
-        Ok(result)
+        Ok(())
     }
 
-    /// This function must be called before the FilesystemExporter leaves scope.
-    /// It is recommended to flush after exporting each DataKey.
+    /// Writes the data buffered for aliasing to the temporary directory. It is recommended to flush
+    /// after exporting each DataKey; [`commit`](FilesystemExporter::commit) flushes the rest.
+    ///
+    /// Flushing also records the data exported so far in the manifest, which FsDataProvider uses
+    /// to answer requests for missing data without touching the filesystem.
     pub fn flush(&mut self) -> Result<(), Error> {
         if let Some(mut alias_collection) = self.alias_collection.take() {
             alias_collection.flush()?;
         }
+        self.written_files.clear();
+        self.write_manifest()
+    }
+
+    /// Flushes the exporter and moves the exported data into the output directory, replacing the
+    /// existing directory according to the [`OverwriteOption`]. Consumes the FilesystemExporter.
+    ///
+    /// The new directory is renamed into place, so the output directory contains either the old
+    /// data or the complete new data. When replacing an existing directory, the output directory
+    /// briefly does not exist between the two renames. The old data is then removed on a best
+    /// effort basis; if that fails, it is left in a hidden sibling directory, which the next
+    /// commit removes.
+    pub fn commit(mut self) -> Result<(), Error> {
+        self.flush()?;
+        if self.output_root.exists() {
+            match self.overwrite {
+                OverwriteOption::CheckEmpty => {
+                    fs::remove_dir(&self.output_root)?;
+                    fs::rename(&self.root, &self.output_root)?;
+                }
+                OverwriteOption::RemoveAndReplace => {
+                    let old_root = get_sibling_path(&self.output_root, "old")?;
+                    if old_root.exists() {
+                        fs::remove_dir_all(&old_root)?;
+                    }
+                    fs::rename(&self.output_root, &old_root)?;
+                    if let Err(err) = fs::rename(&self.root, &self.output_root) {
+                        // Put the old data back; the new data is removed on drop.
+                        let _ = fs::rename(&old_root, &self.output_root);
+                        return Err(err.into());
+                    }
+                    self.committed = true;
+                    if let Err(err) = fs::remove_dir_all(&old_root) {
+                        if self.verbose {
+                            println!(
+                                "Could not remove old data: {}: {}",
+                                old_root.to_string_lossy(),
+                                err
+                            );
+                        }
+                    }
+                    return Ok(());
+                }
+            }
+        } else {
+            fs::rename(&self.root, &self.output_root)?;
+        }
+        self.committed = true;
         Ok(())
     }
 
     fn write_to_path(
         &mut self,
+        req: &DataRequest,
         mut path_buf: PathBuf,
-        obj: &dyn erased_serde::Serialize,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let file_extension = self.serializer.get_file_extension();
+        buf: Vec<u8>,
+        hash: String,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let file_extension = self.manifest.get_data_file_extension();
         match self.manifest.aliasing {
             AliasOption::NoAliases => {
-                path_buf.set_extension(file_extension);
+                path_buf.set_extension(&file_extension);
                 if let Some(parent_dir) = path_buf.parent() {
                     fs::create_dir_all(&parent_dir)?;
                 }
-                let mut file = fs::File::create(&path_buf)?;
-                self.serializer.serialize(obj, &mut file)?;
+                fs::write(&path_buf, buf)?;
             }
             AliasOption::Symlink => {
-                let mut buf: Vec<u8> = Vec::new();
-                self.serializer.serialize(obj, &mut buf)?;
                 let mut alias_root = path_buf.clone();
                 assert!(alias_root.pop());
                 self.alias_collection
                     .get_or_insert_with(|| {
                         AliasCollection::new(aliasing::Options {
                             root: alias_root,
-                            symlink_file_extension: "l",
+                            symlink_file_extension: SYMLINK_FILE_EXTENSION,
                             data_file_prefix: "data",
-                            data_file_extension: file_extension,
+                            data_file_extension: &file_extension,
                         })
                     })
                     .put(path_buf, buf);
             }
+            AliasOption::HardLink | AliasOption::AliasTable => {
+                path_buf.set_extension(&file_extension);
+                let mut dir = path_buf.clone();
+                assert!(dir.pop());
+                // Like symlinks, aliases are only made within the directory of a DataKey or variant.
+                match self.written_files.entry((dir, hash)) {
+                    Entry::Occupied(written_file) => {
+                        let (target_path, target_entry) = written_file.get();
+                        if self.manifest.aliasing == AliasOption::HardLink {
+                            fs::hard_link(target_path, &path_buf)?;
+                        } else {
+                            // The alias table is written to the manifest on flush.
+                            self.manifest
+                                .aliases
+                                .get_or_insert_with(BTreeMap::new)
+                                .entry(req.data_key.to_string())
+                                .or_default()
+                                .insert(req.data_entry.to_string(), target_entry.clone());
+                        }
+                    }
+                    Entry::Vacant(written_file) => {
+                        fs::create_dir_all(written_file.key().0.as_path())?;
+                        fs::write(&path_buf, buf)?;
+                        written_file.insert((path_buf, req.data_entry.to_string()));
+                    }
+                }
+            }
         }
 
         Ok(())
     }
 }
+
+/// Compresses a serialized payload. The compression was checked to be enabled in try_new.
+#[allow(unused_mut)]
+pub(crate) fn compress(compression: CompressionOption, mut buf: Vec<u8>) -> Result<Vec<u8>, Error> {
+    match compression {
+        CompressionOption::None => Ok(buf),
+        #[cfg(feature = "flate2")]
+        CompressionOption::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(&buf)?;
+            Ok(encoder.finish()?)
+        }
+        #[cfg(feature = "zstd")]
+        CompressionOption::Zstd => Ok(zstd::encode_all(buf.as_slice(), 0)?),
+        #[allow(unreachable_patterns)]
+        _ => Err(Error::UnsupportedCompression(compression)),
+    }
+}
+
+/// Checks that the output directory can be replaced according to the OverwriteOption, so that
+/// the export fails before any data is written.
+fn check_output_root(output_root: &Path, overwrite: OverwriteOption) -> Result<(), Error> {
+    match overwrite {
+        OverwriteOption::CheckEmpty => {
+            if output_root.exists() && fs::read_dir(output_root)?.next().is_some() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!(
+                        "Output directory is not empty: {}",
+                        output_root.to_string_lossy()
+                    ),
+                )
+                .into());
+            }
+        }
+        OverwriteOption::RemoveAndReplace => {}
+    };
+    if let Some(parent_dir) = output_root.parent() {
+        fs::create_dir_all(parent_dir)?;
+    }
+    Ok(())
+}
+
+/// Returns a hidden path next to the output directory, such as ".icu4x_data.partial" for
+/// "icu4x_data". It is on the same filesystem, so that it can be renamed to the output directory.
+pub(super) fn get_sibling_path(output_root: &Path, suffix: &str) -> Result<PathBuf, Error> {
+    let file_name = output_root.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Output directory has no file name: {}",
+                output_root.to_string_lossy()
+            ),
+        )
+    })?;
+    let mut sibling_name = std::ffi::OsString::from(".");
+    sibling_name.push(file_name);
+    sibling_name.push(".");
+    sibling_name.push(suffix);
+    Ok(output_root.with_file_name(sibling_name))
+}
@@ -1,6 +1,15 @@
 //! The `export` feature enables you to pull all data from some other data provider and persist it
 //! on the filesystem to be read by an FsDataProvider at runtime.
 //!
+//! Alternatively, [`RustExporter`] writes the data as Rust source code to be compiled into the
+//! binary, so that no I/O is needed at runtime.
+//!
+//! [`ArchiveExporter`] writes the data directory into a zip or tar archive instead, to be read by
+//! `FsDataProvider::try_new_archive`.
+//!
+//! [`DryRunExporter`] writes nothing, but reports the number and size of the payloads that would be
+//! exported.
+//!
 //! Also see the binary "icu4x-cldr-export".
 //!
 //! # Examples
@@ -8,7 +17,7 @@
 //! ```
 //! use icu_data_provider::prelude::*;
 //! use icu_data_provider::InvariantDataProvider;
-//! use icu_data_provider::structs::plurals::PluralRuleStringsV1;
+//! use icu_data_provider::structs::plurals::{self, PluralRuleStringsV1};
 //! use icu_data_provider::iter::IterableDataProvider;
 //! use icu_fs_data_provider::FsDataProvider;
 //! use icu_fs_data_provider::export::fs_exporter;
@@ -16,7 +25,7 @@
 //! use std::path::PathBuf;
 //!
 //! let DEMO_PATH = std::env::temp_dir().join("icu4x_json_demo");
-//! let DATA_KEY = icu_data_key!(plurals: cardinal@1);
+//! let DATA_KEY = plurals::key::CARDINAL_V1;
 //!
 //! // Set up the exporter
 //! let mut options = serializers::JsonSerializerOptions::default();
@@ -28,10 +37,12 @@
 //!
 //! // Export a key
 //! let inv_provider = InvariantDataProvider;
-//! let result = inv_provider.export_key(&DATA_KEY, &mut exporter);
-//! // Ensure flush() is called, even when the result is an error
-//! exporter.flush().expect("Should successfully flush");
-//! result.expect("Should successfully export");
+//! inv_provider
+//!     .export_key(&DATA_KEY, &mut exporter)
+//!     .expect("Should successfully export");
+//!
+//! // Move the data into the demo directory
+//! exporter.commit().expect("Should successfully commit");
 //!
 //! // Create a filesystem provider reading from the demo directory
 //! let fs_provider = FsDataProvider::try_new(DEMO_PATH.clone())
@@ -59,6 +70,12 @@
 //! ```
 
 mod aliasing;
+pub mod archive_exporter;
+pub mod dry_run;
 pub mod fs_exporter;
+pub mod rust_exporter;
 pub mod serializers;
+pub use archive_exporter::ArchiveExporter;
+pub use dry_run::DryRunExporter;
 pub use fs_exporter::FilesystemExporter;
+pub use rust_exporter::RustExporter;
@@ -0,0 +1,293 @@
+use crate::error::Error;
+use icu_data_provider::bake::BakedPayload;
+use icu_data_provider::iter::DataExporter;
+use icu_data_provider::prelude::*;
+use icu_data_provider::structs;
+use std::collections::{BTreeMap, HashSet};
+use std::io::Write;
+
+/// Options bag for initializing a RustExporter.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RustExporterOptions {
+    /// Name of the generated data provider struct.
+    pub provider_name: String,
+    /// Version of the exported data, such as the CLDR version, to return in response metadata.
+    pub data_version: Option<String>,
+}
+
+impl Default for RustExporterOptions {
+    fn default() -> Self {
+        Self {
+            provider_name: "BakedDataProvider".to_string(),
+            data_version: None,
+        }
+    }
+}
+
+/// A data exporter that writes data as Rust source code, also known as "baked data".
+///
+/// The output is a module containing a `static` item for each payload, along with a unit struct
+/// implementing `DataProvider<'static>` and `DataEntryCollection` that serves the data. Payloads
+/// that can be constructed in a constant expression are initialized at compile time; other
+/// payloads are in a `LazyLock`, which constructs them on first use. Payloads are returned with
+/// `with_borrowed_payload`, so loading them does not allocate, and no I/O is performed at runtime.
+///
+/// The generated module depends only on `icu_data_provider` and `icu_locale`. Call
+/// [`close`](RustExporter::close) after exporting all keys to write the module to the sink.
+/// See the module-level docs for an example.
+pub struct RustExporter<W: Write> {
+    sink: W,
+    options: RustExporterOptions,
+    /// Baked payloads by DataKey and DataEntry, in string form so that the output is sorted.
+    payloads: BTreeMap<String, (DataKey, EntryPayloads)>,
+}
+
+/// Baked payloads of a DataKey by DataEntry, keyed by the DataEntry in string form.
+type EntryPayloads = BTreeMap<String, (DataEntry, BakedPayload)>;
+
+impl<W: Write> DataExporter for RustExporter<W> {
+    fn put(
+        &mut self,
+        req: &DataRequest,
+        obj: &dyn erased_serde::Serialize,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let bake = structs::get_baker(&req.data_key).ok_or(DataError::from(&req.data_key))?;
+        // Round-trip the payload through JSON to recover the data struct.
+        let json = serde_json::to_vec(obj)?;
+        let mut deserializer = serde_json::Deserializer::from_slice(&json);
+        let baked = bake(&mut <dyn erased_serde::Deserializer>::erase(
+            &mut deserializer,
+        ))?;
+        self.payloads
+            .entry(req.data_key.to_string())
+            .or_insert_with(|| (req.data_key, BTreeMap::new()))
+            .1
+            .insert(req.data_entry.to_string(), (req.data_entry.clone(), baked));
+        Ok(())
+    }
+}
+
+impl<W: Write> RustExporter<W> {
+    pub fn new(sink: W, options: &RustExporterOptions) -> Self {
+        Self {
+            sink,
+            options: options.clone(),
+            payloads: BTreeMap::new(),
+        }
+    }
+
+    /// Writes the generated module to the sink, and returns the sink.
+    pub fn close(mut self) -> Result<W, Error> {
+        let source = self.generate();
+        self.sink.write_all(source.as_bytes())?;
+        self.sink.flush()?;
+        Ok(self.sink)
+    }
+
+    fn generate(&self) -> String {
+        let provider = &self.options.provider_name;
+        let mut statics = String::new();
+        let mut key_consts = String::new();
+        let mut load_arms = String::new();
+        let mut iter_arms = String::new();
+        let mut categories: Vec<DataCategory> = vec![];
+        let mut static_names: HashSet<String> = HashSet::new();
+
+        for (key_str, (data_key, entries)) in self.payloads.iter() {
+            let key_const = get_identifier("KEY", key_str, &mut static_names);
+            key_consts.push_str(&format!(
+                "const {}: DataKey = DataKey::new({}, {:?}, {});\n",
+                key_const,
+                bake_category(&data_key.category),
+                data_key.sub_category.as_str(),
+                data_key.version
+            ));
+            if !categories.contains(&data_key.category) {
+                categories.push(data_key.category);
+            }
+
+            let mut entry_arms = String::new();
+            for (entry_str, (data_entry, baked)) in entries.iter() {
+                let static_name = get_identifier(
+                    "DATA",
+                    &format!("{}/{}", key_str, entry_str),
+                    &mut static_names,
+                );
+                let payload = if baked.is_const {
+                    statics.push_str(&format!(
+                        "/// Data for `{}/{}`.\nstatic {}: {} = {};\n\n",
+                        key_str, entry_str, static_name, baked.type_path, baked.expression
+                    ));
+                    format!("&{}", static_name)
+                } else {
+                    statics.push_str(&format!(
+                        "/// Data for `{}/{}`.\nstatic {}: ::std::sync::LazyLock<{}> =\n    \
+                         ::std::sync::LazyLock::new(|| {});\n\n",
+                        key_str, entry_str, static_name, baked.type_path, baked.expression
+                    ));
+                    format!("&*{}", static_name)
+                };
+                entry_arms.push_str(&format!(
+                    "                {} => builder.with_borrowed_payload({}),\n",
+                    bake_entry_pattern(data_entry),
+                    payload
+                ));
+            }
+            load_arms.push_str(&format!(
+                "            {} => match subtags {{\n\
+                 {}                _ => return Err(DataError::UnavailableEntry(req.clone())),\n\
+                 \x20           }},\n",
+                key_const, entry_arms
+            ));
+            let entry_strs: Vec<String> = entries.keys().map(|s| format!("{:?}", s)).collect();
+            iter_arms.push_str(&format!(
+                "            {} => &[{}],\n",
+                key_const,
+                entry_strs.join(", ")
+            ));
+        }
+
+        let categories: Vec<String> = categories.iter().map(bake_category).collect();
+        let data_version = match &self.options.data_version {
+            Some(version) => format!("Some(::std::borrow::Cow::Borrowed({:?}))", version),
+            None => "None".to_string(),
+        };
+
+        format!(
+            r#"// @generated by icu_fs_data_provider::export::RustExporter. Do not edit.
+
+use icu_data_provider::iter::DataEntryCollection;
+use icu_data_provider::prelude::*;
+
+{key_consts}
+/// Data categories with at least one data key in this module.
+const CATEGORIES: &[DataCategory] = &[{categories}];
+
+{statics}/// Returns whether the DataEntry has exactly the given language variants.
+#[allow(dead_code)]
+fn has_variants(data_entry: &DataEntry, variants: &[&str]) -> bool {{
+    data_entry
+        .langid
+        .variants
+        .iter()
+        .map(|variant| variant.as_str())
+        .eq(variants.iter().copied())
+}}
+
+/// A data provider serving data compiled into the binary.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct {provider};
+
+impl {provider} {{
+    /// Returns the error for a data key that is not in this module.
+    fn unsupported_key_error(data_key: &DataKey) -> DataError {{
+        if CATEGORIES.contains(&data_key.category) {{
+            DataError::UnsupportedDataKey(*data_key)
+        }} else {{
+            DataError::UnsupportedCategory(data_key.category)
+        }}
+    }}
+}}
+
+impl DataProvider<'static> for {provider} {{
+    #[allow(unreachable_code, unused_variables)]
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {{
+        let builder = DataResponseBuilder {{
+            data_langid: req.data_entry.langid.clone(),
+        }};
+        // The variant, language, script, and region of the DataEntry, to match without
+        // allocating. Language variants are checked separately.
+        let langid = &req.data_entry.langid;
+        let subtags = (
+            req.data_entry.variant.as_deref(),
+            langid.language.as_str(),
+            langid.script.as_ref().map(|script| script.as_str()),
+            langid.region.as_ref().map(|region| region.as_str()),
+        );
+        let mut response = match req.data_key {{
+{load_arms}            _ => return Err(Self::unsupported_key_error(&req.data_key)),
+        }};
+        response.metadata.data_version = {data_version};
+        Ok(response)
+    }}
+}}
+
+impl DataEntryCollection for {provider} {{
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {{
+        let entries: &[&str] = match *data_key {{
+{iter_arms}            _ => return Err(Self::unsupported_key_error(data_key)),
+        }};
+        Ok(Box::new(
+            entries
+                .iter()
+                .map(|s| s.parse().expect("Generated data entries are valid")),
+        ))
+    }}
+}}
+"#,
+            key_consts = key_consts,
+            categories = categories.join(", "),
+            statics = statics,
+            provider = provider,
+            load_arms = load_arms,
+            data_version = data_version,
+            iter_arms = iter_arms,
+        )
+    }
+}
+
+/// Bakes a match pattern for the subtags of a DataEntry, as matched by the generated `load`.
+fn bake_entry_pattern(data_entry: &DataEntry) -> String {
+    let langid = &data_entry.langid;
+    let variants: Vec<String> = langid
+        .variants
+        .iter()
+        .map(|variant| format!("{:?}", variant.as_str()))
+        .collect();
+    format!(
+        "({:?}, {:?}, {:?}, {:?}) if has_variants(&req.data_entry, &[{}])",
+        data_entry.variant.as_deref(),
+        langid.language.as_str(),
+        langid.script.as_ref().map(|script| script.as_str()),
+        langid.region.as_ref().map(|region| region.as_str()),
+        variants.join(", ")
+    )
+}
+
+/// Bakes a DataCategory as a constant expression.
+fn bake_category(category: &DataCategory) -> String {
+    match category {
+        DataCategory::PrivateUse(id) => {
+            format!("DataCategory::new_private_use({:?})", id.as_str())
+        }
+        // The Debug form of the other variants is their name.
+        _ => format!("DataCategory::{:?}", category),
+    }
+}
+
+/// Builds a unique identifier for a generated item from a prefix and a string such as a data
+/// request, replacing characters that are not allowed in identifiers.
+fn get_identifier(prefix: &str, s: &str, used: &mut HashSet<String>) -> String {
+    let sanitized: String = s
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let base = format!("{}_{}", prefix, sanitized);
+    let mut identifier = base.clone();
+    let mut suffix = 1;
+    while !used.insert(identifier.clone()) {
+        suffix += 1;
+        identifier = format!("{}_{}", base, suffix);
+    }
+    identifier
+}
@@ -84,3 +84,91 @@ impl JsonSerializer {
         }
     }
 }
+
+/// A serializer for bincode, a compact binary format. Requires the `bincode` feature.
+#[cfg(feature = "bincode")]
+pub struct BincodeSerializer {
+    syntax: SyntaxOption,
+}
+
+#[cfg(feature = "bincode")]
+impl Deref for BincodeSerializer {
+    type Target = SyntaxOption;
+
+    fn deref(&self) -> &Self::Target {
+        &self.syntax
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl Serializer for BincodeSerializer {
+    fn serialize(
+        &self,
+        obj: &dyn erased_serde::Serialize,
+        sink: &mut dyn io::Write,
+    ) -> Result<(), Error> {
+        use bincode::Options;
+        bincode::DefaultOptions::new().serialize_into(sink, obj)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl BincodeSerializer {
+    pub fn new() -> Self {
+        Self {
+            syntax: SyntaxOption::Bincode,
+        }
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl Default for BincodeSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A serializer for postcard, a compact binary format designed for embedded devices. Requires
+/// the `postcard` feature.
+#[cfg(feature = "postcard")]
+pub struct PostcardSerializer {
+    syntax: SyntaxOption,
+}
+
+#[cfg(feature = "postcard")]
+impl Deref for PostcardSerializer {
+    type Target = SyntaxOption;
+
+    fn deref(&self) -> &Self::Target {
+        &self.syntax
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl Serializer for PostcardSerializer {
+    fn serialize(
+        &self,
+        obj: &dyn erased_serde::Serialize,
+        sink: &mut dyn io::Write,
+    ) -> Result<(), Error> {
+        postcard::to_io(obj, sink)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl PostcardSerializer {
+    pub fn new() -> Self {
+        Self {
+            syntax: SyntaxOption::Postcard,
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl Default for PostcardSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
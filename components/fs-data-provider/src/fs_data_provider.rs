@@ -1,18 +1,66 @@
+use crate::archive::Archive;
 use crate::error::Error;
+use crate::manifest::AliasOption;
+use crate::manifest::CompressionOption;
 use crate::manifest::Manifest;
+use crate::manifest::SyntaxOption;
 use crate::manifest::MANIFEST_FILE;
+use crate::manifest::SYMLINK_FILE_EXTENSION;
+#[cfg(feature = "async")]
+use async_trait::async_trait;
+use icu_data_provider::iter::DataEntryCollection;
 use icu_data_provider::prelude::*;
 use icu_data_provider::structs;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
 use std::fs;
 use std::fs::File;
 use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// A data provider that reads ICU4X data from a filesystem directory.
-#[derive(Debug, PartialEq)]
+///
+/// Data files are located at `<root>/<category>/<sub_category>@<version>/[<variant>/]<langid>`,
+/// with the file extension of the syntax in the manifest, followed by the file extension of the
+/// compression, if any, such as `.json.gz`. If the directory was exported with
+/// `AliasOption::Symlink`, each DataEntry is instead a symlink with the extension `.l` pointing to
+/// a shared data file. If it was exported with `AliasOption::AliasTable`, DataEntries that share
+/// the data file of another DataEntry are listed in the manifest, and have no file of their own.
+///
+/// The manifest written by the exporter lists the exported DataKeys and DataEntries. If it is
+/// present, FsDataProvider answers requests for data that is not in the directory, and lists the
+/// DataEntries for `DataEntryCollection`, without touching the filesystem. Otherwise, it checks
+/// for the files and walks the DataKey directory instead.
+///
+/// Since FsDataProvider implements `DataEntryCollection`, an existing data directory can be
+/// exported again, for example to convert it to another syntax.
+///
+/// A data directory can also be read from a zip or tar archive with the same layout, without
+/// unpacking it; see [`FsDataProvider::try_new_archive`].
+///
+/// Payloads are always deserialized into owned data, so their strings are copied from the data
+/// files on every load. To borrow them from memory-mapped data instead, export the data to a blob
+/// and read it with `BlobDataProvider` from the `icu-blob-data-provider` crate.
+#[derive(Debug)]
 pub struct FsDataProvider {
+    /// The data directory, or the archive file in archive mode.
     res_root: PathBuf,
     manifest: Manifest,
+    archive: Option<Arc<Archive>>,
+}
+
+/// FsDataProviders are equal if they read from the same data directory or archive, with the same
+/// manifest.
+impl PartialEq for FsDataProvider {
+    fn eq(&self, other: &Self) -> bool {
+        self.res_root == other.res_root
+            && self.manifest == other.manifest
+            && self.archive.is_some() == other.archive.is_some()
+    }
 }
 
 impl FsDataProvider {
@@ -25,50 +73,522 @@ impl FsDataProvider {
         Ok(Self {
             res_root: root_path_buf,
             manifest,
+            archive: None,
+        })
+    }
+
+    /// Create a new FsDataProvider reading from an archive of a data directory, with the data
+    /// directory at the root of the archive. The archive format is chosen by the file extension:
+    /// `.zip` requires the `zip` feature, and `.tar` requires the `tar` feature.
+    ///
+    /// Data files are read from the archive on demand. Symlinks in the archive are not followed,
+    /// so use `AliasOption::AliasTable` to de-duplicate data in archives. Also see
+    /// `ArchiveExporter` in the `export` module.
+    pub fn try_new_archive<T: Into<PathBuf>>(path: T) -> Result<Self, Error> {
+        let path_buf: PathBuf = path.into();
+        let archive = Archive::open(&path_buf)?;
+        let manifest_bytes = archive
+            .read(MANIFEST_FILE)?
+            .ok_or_else(|| Error::MissingManifest(path_buf.clone()))?;
+        let manifest: Manifest = serde_json::from_slice(&manifest_bytes)?;
+        Ok(Self {
+            res_root: path_buf,
+            manifest,
+            archive: Some(Arc::new(archive)),
         })
     }
 }
 
-impl DataProvider<'_> for FsDataProvider {
-    fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
-        type Error = DataError;
+impl FsDataProvider {
+    /// Gets the DataKeys that have data in the directory, sorted by their string form.
+    pub fn supported_keys(&self) -> Result<Vec<DataKey>, DataError> {
+        let mut data_keys: Vec<DataKey> = match &self.manifest.keys {
+            Some(keys) => keys
+                .keys()
+                .map(|s| s.parse())
+                .collect::<Result<_, _>>()
+                .map_err(resource_error)?,
+            None => self.collect_keys().map_err(resource_error)?,
+        };
+        data_keys.sort_by_cached_key(|data_key| data_key.to_string());
+        Ok(data_keys)
+    }
+
+    /// Gets the DataEntries that have data for a DataKey, sorted by their string form.
+    pub fn available_entries(&self, data_key: &DataKey) -> Result<Vec<DataEntry>, DataError> {
+        Ok(self.iter_for_key(data_key)?.collect())
+    }
+
+    /// Gets the DataEntries and hashes of a DataKey from the inventory in the manifest, in string
+    /// form. Returns None if the manifest has no inventory, in which case the filesystem needs to
+    /// be checked instead.
+    fn get_inventory(
+        &self,
+        data_key: &DataKey,
+    ) -> Option<Result<&BTreeMap<String, String>, DataError>> {
+        let keys = self.manifest.keys.as_ref()?;
+        Some(match keys.get(&data_key.to_string()) {
+            Some(entries) => Ok(entries),
+            None => {
+                let category = data_key.category.to_string();
+                let category_exists = keys
+                    .keys()
+                    .any(|s| s.split('/').next() == Some(category.as_str()));
+                Err(unsupported_key_error(data_key, category_exists))
+            }
+        })
+    }
+
+    /// Gets the path of the data file for a request, and reads it without decoding it.
+    #[cfg(feature = "verify")]
+    pub(crate) fn read_data_file(
+        &self,
+        req: &DataRequest,
+    ) -> Result<(PathBuf, Vec<u8>), DataError> {
+        let path_buf = self.get_request_path(self.get_key_path(&req.data_key), req)?;
+        let bytes = match &self.archive {
+            Some(archive) => archive
+                .read(&self.get_archive_name(&path_buf))
+                .map_err(resource_error)?
+                .ok_or_else(|| DataError::UnavailableEntry(req.clone()))?,
+            None => fs::read(&path_buf).map_err(resource_error)?,
+        };
+        Ok((path_buf, bytes))
+    }
+
+    /// Gets the hash of the data file for a request from the inventory in the manifest.
+    #[cfg(feature = "verify")]
+    pub(crate) fn get_recorded_hash(&self, req: &DataRequest) -> Option<&str> {
+        self.manifest
+            .keys
+            .as_ref()?
+            .get(&req.data_key.to_string())?
+            .get(&req.data_entry.to_string())
+            .map(String::as_str)
+    }
+
+    /// Gets the path of the directory containing all data for a DataKey.
+    fn get_key_path(&self, data_key: &DataKey) -> PathBuf {
         let mut path_buf = self.res_root.clone();
-        path_buf.extend(req.data_key.get_components().iter());
-        if !path_buf.exists() {
-            path_buf.pop();
-            if !path_buf.exists() {
-                return Err(Error::UnsupportedCategory(req.data_key.category));
-            } else {
-                return Err(Error::UnsupportedDataKey(req.data_key));
+        path_buf.extend(data_key.get_components().iter());
+        path_buf
+    }
+
+    /// Gets the name of a file in the archive from its path, which starts with the path of the
+    /// archive.
+    fn get_archive_name(&self, path: &Path) -> String {
+        let relative_path = path
+            .strip_prefix(&self.res_root)
+            .expect("The path is in the archive");
+        let components: Vec<_> = relative_path.iter().map(OsStr::to_string_lossy).collect();
+        components.join("/")
+    }
+
+    /// Returns whether a directory exists in the data directory or archive.
+    fn dir_exists(&self, path: &Path) -> bool {
+        match &self.archive {
+            Some(archive) => archive.dir_exists(&self.get_archive_name(path)),
+            None => path.is_dir(),
+        }
+    }
+
+    /// Gets the path of the data file for a request, checking the inventory in the manifest or the
+    /// directories that the data file would be in. In archive mode, this does not touch the
+    /// filesystem.
+    fn find_data_file(&self, req: &DataRequest) -> Result<PathBuf, DataError> {
+        let key_path = self.get_key_path(&req.data_key);
+        // Locale fallback is performed by wrapping this provider in LocaleFallbackProvider.
+        match self.get_inventory(&req.data_key) {
+            Some(entries) => {
+                if !entries?.contains_key(&req.data_entry.to_string()) {
+                    return Err(DataError::UnavailableEntry(req.clone()));
+                }
+                self.get_request_path(key_path, req)
+            }
+            None => {
+                if !self.dir_exists(&key_path) {
+                    let category_exists = match key_path.parent() {
+                        Some(p) => self.dir_exists(p),
+                        None => false,
+                    };
+                    return Err(unsupported_key_error(&req.data_key, category_exists));
+                }
+                let path_buf = self.get_request_path(key_path, req)?;
+                // Missing files in archives are detected when reading them.
+                if self.archive.is_none() && !path_buf.exists() {
+                    return Err(DataError::UnavailableEntry(req.clone()));
+                }
+                Ok(path_buf)
             }
         }
-        // TODO: Implement proper locale fallback
-        path_buf.extend(req.data_entry.get_components().iter());
-        path_buf.set_extension(self.manifest.syntax.get_file_extension());
-        if !path_buf.exists() {
-            return Err(Error::UnavailableEntry(req.clone()));
+    }
+
+    /// Deserializes a data file read from the archive, or returns UnavailableEntry if the archive
+    /// does not contain it.
+    fn deserialize_archive_file(
+        &self,
+        req: &DataRequest,
+        bytes: Option<Vec<u8>>,
+    ) -> Result<DataResponse<'static>, DataError> {
+        let bytes = bytes.ok_or_else(|| DataError::UnavailableEntry(req.clone()))?;
+        let reader = decompress(self.manifest.compression, bytes.as_slice())?;
+        deserialize_response(&self.manifest.syntax, req, reader)
+    }
+
+    /// Gets the metadata for a response read from the given data file.
+    fn get_metadata(&self, path: &Path) -> DataResponseMetadata {
+        let mut metadata = DataResponseMetadata::default();
+        metadata.source = Some(Cow::Owned(path.to_string_lossy().into_owned()));
+        metadata.data_version = self.manifest.data_version.clone().map(Cow::Owned);
+        metadata
+    }
+
+    /// Gets the file extension of the file for each DataEntry, which is a symlink to the data
+    /// file when aliasing is enabled.
+    fn get_entry_extension(&self) -> String {
+        match self.manifest.aliasing {
+            AliasOption::Symlink => SYMLINK_FILE_EXTENSION.to_string(),
+            _ => self.manifest.get_data_file_extension(),
         }
-        let file = match File::open(&path_buf) {
-            Ok(file) => file,
-            Err(err) => return Err(Error::ResourceError(Box::new(err))),
-        };
-        let reader = BufReader::new(file);
-        // TODO: Eliminate this dispatch.
-        // https://github.com/unicode-org/icu4x/issues/196
-        if req.data_key.category == DataCategory::Plurals {
-            // TODO: Pick deserializer based on manifest
-            let obj: structs::plurals::PluralRuleStringsV1 = match serde_json::from_reader(reader) {
-                Ok(obj) => obj,
-                Err(err) => return Err(Error::ResourceError(Box::new(err))),
+    }
+
+    /// Gets the path of the data file for a DataEntry within a DataKey directory.
+    fn get_entry_path(&self, mut key_path: PathBuf, data_entry: &DataEntry) -> PathBuf {
+        key_path.extend(data_entry.get_components().iter());
+        key_path.set_extension(self.get_entry_extension());
+        key_path
+    }
+
+    /// Gets the path of the data file for a request within its DataKey directory, which belongs
+    /// to another DataEntry if the requested one is in the alias table of the manifest.
+    fn get_request_path(&self, key_path: PathBuf, req: &DataRequest) -> Result<PathBuf, DataError> {
+        let target = self
+            .manifest
+            .aliases
+            .as_ref()
+            .and_then(|aliases| aliases.get(&req.data_key.to_string()))
+            .and_then(|entries| entries.get(&req.data_entry.to_string()));
+        match target {
+            Some(target) => {
+                let target: DataEntry = target.parse().map_err(resource_error)?;
+                Ok(self.get_entry_path(key_path, &target))
+            }
+            None => Ok(self.get_entry_path(key_path, &req.data_entry)),
+        }
+    }
+
+    /// Gets the DataKeys of the DataKey directories in the root directory.
+    fn collect_keys(&self) -> Result<Vec<DataKey>, Error> {
+        if let Some(archive) = &self.archive {
+            return collect_archive_keys(archive);
+        }
+        let mut data_keys = vec![];
+        for category_entry in fs::read_dir(&self.res_root)? {
+            let category_path = category_entry?.path();
+            if !category_path.is_dir() {
+                continue;
+            }
+            for key_entry in fs::read_dir(&category_path)? {
+                let key_path = key_entry?.path();
+                if !key_path.is_dir() {
+                    continue;
+                }
+                let relative_path = key_path
+                    .strip_prefix(&self.res_root)
+                    .expect("The DataKey directory is in the root directory");
+                let components: Vec<_> = relative_path.iter().map(OsStr::to_string_lossy).collect();
+                data_keys.push(components.join("/").parse()?);
+            }
+        }
+        Ok(data_keys)
+    }
+
+    /// Adds the DataEntries of the files in a DataKey directory, or in a variant directory within
+    /// it, to the list.
+    fn collect_entries(
+        &self,
+        dir: &Path,
+        variant: Option<&str>,
+        entries: &mut Vec<DataEntry>,
+    ) -> Result<(), Error> {
+        // The extension may have several parts, such as "json.gz", so compare the suffix.
+        let suffix = format!(".{}", self.get_entry_extension());
+        for dir_entry in fs::read_dir(dir)? {
+            let path = dir_entry?.path();
+            let file_name = match path.file_name().and_then(OsStr::to_str) {
+                Some(file_name) => file_name,
+                None => continue,
             };
-            let response = DataResponseBuilder {
-                // TODO: Return the actual locale when fallbacks are implemented.
-                data_langid: req.data_entry.langid.clone(),
+            if path.is_dir() {
+                // Variants cannot contain '/', so only the top level can have variant directories.
+                if variant.is_none() {
+                    self.collect_entries(&path, Some(file_name), entries)?;
+                }
+                continue;
             }
-            .with_owned_payload(obj);
+            let langid = match file_name.strip_suffix(&suffix) {
+                Some(langid) => langid,
+                // Skip other files, such as the data files that aliases point to.
+                None => continue,
+            };
+            // Resolve the alias, failing if it is a broken symlink.
+            fs::metadata(&path)?;
+            let data_entry = match variant {
+                Some(variant) => format!("{}/{}", variant, langid).parse()?,
+                None => langid.parse()?,
+            };
+            entries.push(data_entry);
+        }
+        Ok(())
+    }
+}
+
+/// Gets the DataKeys of the DataKey directories in an archive.
+fn collect_archive_keys(archive: &Archive) -> Result<Vec<DataKey>, Error> {
+    let mut key_names: Vec<String> = archive
+        .file_names()
+        .iter()
+        .filter_map(|name| {
+            // Only directories two levels deep that contain files are DataKey directories.
+            let mut components = name.splitn(3, '/');
+            let category = components.next()?;
+            let sub_category = components.next()?;
+            components.next()?;
+            Some(format!("{}/{}", category, sub_category))
+        })
+        .collect();
+    key_names.sort();
+    key_names.dedup();
+    let mut data_keys = vec![];
+    for key_name in key_names {
+        data_keys.push(key_name.parse()?);
+    }
+    Ok(data_keys)
+}
+
+/// Adds the DataEntries of the files with the given suffix in a DataKey directory of an archive,
+/// or in a variant directory within it, to the list.
+fn collect_archive_entries(
+    archive: &Archive,
+    prefix: &str,
+    suffix: &str,
+    entries: &mut Vec<DataEntry>,
+) -> Result<(), Error> {
+    for name in archive.file_names() {
+        let entry_name = match name
+            .strip_prefix(prefix)
+            .and_then(|name| name.strip_suffix(suffix))
+        {
+            Some(entry_name) => entry_name,
+            None => continue,
+        };
+        // Variants cannot contain '/', so only the top level can have variant directories.
+        if entry_name.matches('/').count() > 1 {
+            continue;
+        }
+        entries.push(entry_name.parse()?);
+    }
+    Ok(())
+}
+
+/// Wraps an error in a DataError.
+fn resource_error<E: Into<Error>>(err: E) -> DataError {
+    DataError::ResourceError(Box::new(err.into()))
+}
+
+/// Returns the error for a DataKey directory that does not exist.
+fn unsupported_key_error(data_key: &DataKey, category_exists: bool) -> DataError {
+    if category_exists {
+        DataError::UnsupportedDataKey(*data_key)
+    } else {
+        DataError::UnsupportedCategory(data_key.category)
+    }
+}
+
+/// Deserializes the payload of a request using the deserializer registered for its DataKey in
+/// the `structs` module.
+fn deserialize_payload(
+    req: &DataRequest,
+    deserializer: &mut dyn erased_serde::Deserializer,
+) -> Result<DataResponse<'static>, DataError> {
+    structs::deserialize_response(
+        &req.data_key,
+        DataResponseBuilder {
+            data_langid: req.data_entry.langid.clone(),
+        },
+        deserializer,
+    )
+}
+
+/// Wraps the reader of a data file to decompress it with the compression from the manifest.
+fn decompress<'a, R: Read + 'a>(
+    compression: CompressionOption,
+    reader: R,
+) -> Result<Box<dyn Read + 'a>, DataError> {
+    match compression {
+        CompressionOption::None => Ok(Box::new(reader)),
+        #[cfg(feature = "flate2")]
+        CompressionOption::Gzip => Ok(Box::new(flate2::read::GzDecoder::new(reader))),
+        #[cfg(feature = "zstd")]
+        CompressionOption::Zstd => Ok(Box::new(
+            zstd::stream::read::Decoder::new(reader).map_err(resource_error)?,
+        )),
+        #[allow(unreachable_patterns)]
+        _ => Err(resource_error(Error::UnsupportedCompression(compression))),
+    }
+}
+
+/// Deserializes the data file for a request into a response, decoding it with the syntax from
+/// the manifest.
+fn deserialize_response<R: Read>(
+    syntax: &SyntaxOption,
+    req: &DataRequest,
+    #[allow(unused_mut)] mut reader: R,
+) -> Result<DataResponse<'static>, DataError> {
+    match syntax {
+        SyntaxOption::Json => {
+            let mut deserializer = serde_json::Deserializer::from_reader(reader);
+            let response = deserialize_payload(
+                req,
+                &mut <dyn erased_serde::Deserializer>::erase(&mut deserializer),
+            )?;
+            deserializer.end().map_err(resource_error)?;
             Ok(response)
-        } else {
-            panic!("Don't know how to parse this data key, but it is on the filesystem");
         }
+        #[cfg(feature = "bincode")]
+        SyntaxOption::Bincode => {
+            let mut deserializer =
+                bincode::Deserializer::with_reader(reader, bincode::DefaultOptions::new());
+            deserialize_payload(
+                req,
+                &mut <dyn erased_serde::Deserializer>::erase(&mut deserializer),
+            )
+        }
+        #[cfg(feature = "postcard")]
+        SyntaxOption::Postcard => {
+            // postcard can only deserialize from a byte slice.
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).map_err(resource_error)?;
+            let mut deserializer = postcard::Deserializer::from_bytes(&bytes);
+            deserialize_payload(
+                req,
+                &mut <dyn erased_serde::Deserializer>::erase(&mut deserializer),
+            )
+        }
+        #[allow(unreachable_patterns)]
+        _ => Err(resource_error(Error::UnsupportedSyntax(syntax.clone()))),
+    }
+}
+
+impl DataProvider<'_> for FsDataProvider {
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+        let path_buf = self.find_data_file(req)?;
+        let mut response = match &self.archive {
+            Some(archive) => {
+                let bytes = archive
+                    .read(&self.get_archive_name(&path_buf))
+                    .map_err(resource_error)?;
+                self.deserialize_archive_file(req, bytes)?
+            }
+            None => {
+                let file = match File::open(&path_buf) {
+                    Ok(file) => file,
+                    Err(err) => return Err(DataError::ResourceError(Box::new(err))),
+                };
+                let reader = decompress(self.manifest.compression, BufReader::new(file))?;
+                deserialize_response(&self.manifest.syntax, req, reader)?
+            }
+        };
+        response.metadata = self.get_metadata(&path_buf);
+        Ok(response)
+    }
+}
+
+impl DataEntryCollection for FsDataProvider {
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {
+        if let Some(entries) = self.get_inventory(data_key) {
+            let entries: Vec<DataEntry> = entries?
+                .keys()
+                .map(|s| s.parse())
+                .collect::<Result<_, _>>()
+                .map_err(resource_error)?;
+            return Ok(Box::new(entries.into_iter()));
+        }
+        let key_path = self.get_key_path(data_key);
+        if !self.dir_exists(&key_path) {
+            let category_exists = match key_path.parent() {
+                Some(p) => self.dir_exists(p),
+                None => false,
+            };
+            return Err(unsupported_key_error(data_key, category_exists));
+        }
+        let mut entries = vec![];
+        match &self.archive {
+            Some(archive) => {
+                let prefix = format!("{}/", self.get_archive_name(&key_path));
+                let suffix = format!(".{}", self.get_entry_extension());
+                collect_archive_entries(archive, &prefix, &suffix, &mut entries)
+            }
+            None => self.collect_entries(&key_path, None, &mut entries),
+        }
+        .map_err(resource_error)?;
+        // Sort the entries so that the order does not depend on the filesystem.
+        entries.sort_by_cached_key(|data_entry| data_entry.to_string());
+        Ok(Box::new(entries.into_iter()))
+    }
+}
+
+/// Reads data files with non-blocking file I/O. In archive mode, the archive is read on a thread
+/// pool, since archive readers block. Requires the `async` feature.
+#[cfg(feature = "async")]
+#[async_trait]
+impl AsyncDataProvider<'static> for FsDataProvider {
+    async fn load_async(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+        type Error = DataError;
+        if let Some(archive) = &self.archive {
+            // The archive index is in memory, but reading the archive blocks, so it is done on a
+            // thread pool.
+            let path_buf = self.find_data_file(req)?;
+            let archive = Arc::clone(archive);
+            let name = self.get_archive_name(&path_buf);
+            let bytes = blocking::unblock(move || archive.read(&name))
+                .await
+                .map_err(resource_error)?;
+            let mut response = self.deserialize_archive_file(req, bytes)?;
+            response.metadata = self.get_metadata(&path_buf);
+            return Ok(response);
+        }
+        let key_path = self.get_key_path(&req.data_key);
+        match self.get_inventory(&req.data_key) {
+            Some(entries) => {
+                if !entries?.contains_key(&req.data_entry.to_string()) {
+                    return Err(Error::UnavailableEntry(req.clone()));
+                }
+            }
+            None => {
+                if async_fs::metadata(&key_path).await.is_err() {
+                    let category_exists = match key_path.parent() {
+                        Some(p) => async_fs::metadata(p).await.is_ok(),
+                        None => false,
+                    };
+                    return Err(unsupported_key_error(&req.data_key, category_exists));
+                }
+            }
+        }
+        let path_buf = self.get_request_path(key_path, req)?;
+        let bytes = match async_fs::read(&path_buf).await {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(Error::UnavailableEntry(req.clone()))
+            }
+            Err(err) => return Err(Error::ResourceError(Box::new(err))),
+        };
+        let reader = decompress(self.manifest.compression, bytes.as_slice())?;
+        let mut response = deserialize_response(&self.manifest.syntax, req, reader)?;
+        response.metadata = self.get_metadata(&path_buf);
+        Ok(response)
     }
 }
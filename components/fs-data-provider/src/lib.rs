@@ -3,6 +3,24 @@
 //! It reads ICU4X data files from the filesystem in a given directory. It can also export data to
 //! the filesystem via an iterable data provider (see the `export` module).
 //!
+//! With the `async` feature, FsDataProvider also implements `AsyncDataProvider`, reading files
+//! with non-blocking I/O.
+//!
+//! Data files are JSON by default. The `bincode` and `postcard` features add compact binary
+//! syntaxes; the syntax of a data directory is recorded in its manifest, so FsDataProvider picks
+//! the matching decoder automatically.
+//!
+//! Data files can also be compressed with gzip or Zstandard, with the `flate2` and `zstd`
+//! features respectively. The compression is likewise recorded in the manifest.
+//!
+//! With the `zip` and `tar` features, `FsDataProvider::try_new_archive` reads a data directory
+//! directly from a zip or tar archive, such as one written by `export::ArchiveExporter`.
+//!
+//! The manifest also lists the DataKeys and DataEntries in the directory, along with a SHA-256
+//! hash of each data file and the version of the data. See `FsDataProvider::supported_keys` and
+//! `FsDataProvider::available_entries`. The `verify` module, enabled by the `verify` feature,
+//! uses them to check a data directory for problems.
+//!
 //! # Examples
 //!
 //! ```
@@ -12,6 +30,7 @@
 //!     .expect_err("Specify a real directoroy in the line above");
 //! ```
 
+mod archive;
 mod error;
 mod fs_data_provider;
 pub mod manifest;
@@ -21,3 +40,6 @@ pub use fs_data_provider::FsDataProvider;
 
 #[cfg(feature = "export")]
 pub mod export;
+
+#[cfg(feature = "verify")]
+pub mod verify;
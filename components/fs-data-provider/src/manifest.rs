@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
 
 /// File name of the manifest. The manifest always uses JSON, even if the serializer isn't JSON.
 pub const MANIFEST_FILE: &str = "manifest.json";
 
+/// File extension of the symlinks for each DataEntry when using `AliasOption::Symlink`.
+pub(crate) const SYMLINK_FILE_EXTENSION: &str = "l";
+
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum AliasOption {
@@ -10,7 +15,12 @@ pub enum AliasOption {
     NoAliases,
     /// De-duplicate data by using filesystem symlinks.
     Symlink,
-    // TODO: Alias based on a field in the JSON file
+    /// De-duplicate data by using filesystem hard links. Each DataEntry is a regular file, so the
+    /// directory can be read like one without aliases.
+    HardLink,
+    /// De-duplicate data by writing each distinct payload once, and listing the DataEntries with
+    /// the same payload as aliases in the manifest. This does not depend on filesystem features.
+    AliasTable,
 }
 
 #[non_exhaustive]
@@ -18,6 +28,11 @@ pub enum AliasOption {
 pub enum SyntaxOption {
     /// Serialize using JavaScript Object Notation (JSON).
     Json,
+    /// Serialize using bincode, a compact binary format. Requires the `bincode` feature.
+    Bincode,
+    /// Serialize using postcard, a compact binary format designed for embedded devices. Requires
+    /// the `postcard` feature.
+    Postcard,
     // Future: Consider adding a custom format option here.
     // Custom {
     //     file_extension: String,
@@ -29,6 +44,82 @@ impl SyntaxOption {
     pub fn get_file_extension(&self) -> &str {
         match self {
             SyntaxOption::Json => "json",
+            SyntaxOption::Bincode => "bincode",
+            SyntaxOption::Postcard => "postcard",
+        }
+    }
+}
+
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+pub enum CompressionOption {
+    /// Do not compress data files.
+    #[default]
+    None,
+    /// Compress data files with gzip. Requires the `flate2` feature.
+    Gzip,
+    /// Compress data files with Zstandard. Requires the `zstd` feature.
+    Zstd,
+}
+
+impl CompressionOption {
+    /// Gets the file extension appended to the extension of the syntax for compressed data files.
+    pub fn get_file_extension(&self) -> Option<&str> {
+        match self {
+            CompressionOption::None => None,
+            CompressionOption::Gzip => Some("gz"),
+            CompressionOption::Zstd => Some("zst"),
+        }
+    }
+
+    /// Returns whether the feature required for the compression is enabled.
+    #[cfg(feature = "export")]
+    pub(crate) fn is_enabled(&self) -> bool {
+        match self {
+            CompressionOption::None => true,
+            CompressionOption::Gzip => cfg!(feature = "flate2"),
+            CompressionOption::Zstd => cfg!(feature = "zstd"),
+        }
+    }
+
+    fn is_none(&self) -> bool {
+        *self == CompressionOption::None
+    }
+}
+
+/// The format of an archive containing a data directory.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ArchiveFormat {
+    /// A zip archive. Requires the `zip` feature.
+    Zip,
+    /// An uncompressed tar archive. Requires the `tar` feature.
+    Tar,
+}
+
+impl ArchiveFormat {
+    /// Gets the file extension associated with the given archive format.
+    pub fn get_file_extension(&self) -> &str {
+        match self {
+            ArchiveFormat::Zip => "zip",
+            ArchiveFormat::Tar => "tar",
+        }
+    }
+
+    /// Gets the archive format from the file extension of a path, if it is known.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "zip" => Some(ArchiveFormat::Zip),
+            "tar" => Some(ArchiveFormat::Tar),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the feature required for the archive format is enabled.
+    pub(crate) fn is_enabled(&self) -> bool {
+        match self {
+            ArchiveFormat::Zip => cfg!(feature = "zip"),
+            ArchiveFormat::Tar => cfg!(feature = "tar"),
         }
     }
 }
@@ -38,4 +129,44 @@ impl SyntaxOption {
 pub(crate) struct Manifest {
     pub aliasing: AliasOption,
     pub syntax: SyntaxOption,
+    /// The compression of the data files. Absent for uncompressed data.
+    #[serde(default, skip_serializing_if = "CompressionOption::is_none")]
+    pub compression: CompressionOption,
+    /// The version of the exported data, such as the CLDR version it was derived from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_version: Option<String>,
+    /// The inventory of the data directory: the exported DataKeys, their DataEntries, and the
+    /// SHA-256 hash of each data file in hexadecimal, all in string form. None for directories
+    /// written before the inventory was added, or if the exporter was not flushed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keys: Option<BTreeMap<String, BTreeMap<String, String>>>,
+    /// The alias table when using `AliasOption::AliasTable`: for each DataKey, the DataEntries
+    /// without a data file of their own, and the DataEntry whose data file they share, all in
+    /// string form.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+impl Manifest {
+    /// Gets the file extension of the data files: the extension of the syntax, followed by the
+    /// extension of the compression, if any.
+    pub fn get_data_file_extension(&self) -> String {
+        let syntax_extension = self.syntax.get_file_extension();
+        match self.compression.get_file_extension() {
+            Some(compression_extension) => {
+                format!("{}.{}", syntax_extension, compression_extension)
+            }
+            None => syntax_extension.to_string(),
+        }
+    }
+}
+
+/// Gets the SHA-256 hash of a data file in hexadecimal, as recorded in the manifest.
+#[cfg(any(feature = "export", feature = "verify"))]
+pub(crate) fn get_hash(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
 }
@@ -0,0 +1,183 @@
+//! The `verify` feature enables you to check a data directory for problems, such as truncated or
+//! hand-edited data files, before deploying it.
+//!
+//! [`verify`] loads every DataEntry of every DataKey in the directory, and checks that:
+//!
+//! - The data file deserializes into the data struct registered for its DataKey.
+//! - The hash of the data file matches the one recorded in the manifest, if any.
+//! - Plural rule strings can be parsed by `icu_pluralrules`.
+//!
+//! All problems are collected in a [`VerifyReport`], rather than stopping at the first one.
+//!
+//! Also see the binary "icu4x-data".
+//!
+//! # Examples
+//!
+//! ```
+//! use icu_fs_data_provider::verify;
+//! use icu_fs_data_provider::FsDataProvider;
+//!
+//! let provider = FsDataProvider::try_new("tests/testdata/json_plurals_37")
+//!     .expect("Loading file from testdata directory");
+//! let report = verify::verify(&provider);
+//! assert!(report.problems.is_empty());
+//! assert_eq!(report.entries_checked, 14);
+//! ```
+
+use crate::manifest;
+use crate::FsDataProvider;
+use icu_data_provider::prelude::*;
+use icu_data_provider::structs::plurals::PluralRuleStringsV1;
+use icu_pluralrules::rules;
+use std::fmt;
+use std::path::PathBuf;
+
+/// A problem found by [`verify`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Problem {
+    /// The DataKeys in the directory, or the DataEntries of a DataKey, could not be listed.
+    ListError(Option<DataKey>, DataError),
+    /// The data file for a request could not be read, or did not deserialize into the data
+    /// struct for its DataKey.
+    LoadError(DataRequest, DataError),
+    /// The hash of the data file does not match the hash in the manifest.
+    ChecksumMismatch {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+    /// A plural rule string could not be parsed.
+    InvalidPluralRule {
+        request: DataRequest,
+        category: &'static str,
+        rule: String,
+        /// The parser error, in its Debug form.
+        error: String,
+    },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::ListError(Some(data_key), error) => {
+                write!(f, "{}: cannot list entries: {}", data_key, error)
+            }
+            Problem::ListError(None, error) => write!(f, "Cannot list data keys: {}", error),
+            Problem::LoadError(req, error) => write!(f, "{}: {}", req, error),
+            Problem::ChecksumMismatch {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{}: checksum mismatch: expected {}, found {}",
+                path.to_string_lossy(),
+                expected,
+                actual
+            ),
+            Problem::InvalidPluralRule {
+                request,
+                category,
+                rule,
+                error,
+            } => write!(
+                f,
+                "{}: invalid plural rule for \"{}\": {}: {}",
+                request, category, error, rule
+            ),
+        }
+    }
+}
+
+/// The result of [`verify`].
+#[non_exhaustive]
+#[derive(Debug, Default)]
+pub struct VerifyReport {
+    /// Number of DataEntries that were loaded.
+    pub entries_checked: usize,
+    /// Number of data files whose hash was compared with the manifest.
+    pub checksums_checked: usize,
+    /// The problems found, in the order they were found.
+    pub problems: Vec<Problem>,
+}
+
+/// Checks every DataEntry in a data directory. See the module-level docs.
+pub fn verify(provider: &FsDataProvider) -> VerifyReport {
+    let mut report = VerifyReport::default();
+    let data_keys = match provider.supported_keys() {
+        Ok(data_keys) => data_keys,
+        Err(err) => {
+            report.problems.push(Problem::ListError(None, err));
+            return report;
+        }
+    };
+    for data_key in data_keys {
+        let data_entries = match provider.available_entries(&data_key) {
+            Ok(data_entries) => data_entries,
+            Err(err) => {
+                report
+                    .problems
+                    .push(Problem::ListError(Some(data_key), err));
+                continue;
+            }
+        };
+        for data_entry in data_entries {
+            let req = DataRequest {
+                data_key,
+                data_entry,
+            };
+            verify_entry(provider, req, &mut report);
+        }
+    }
+    report
+}
+
+fn verify_entry(provider: &FsDataProvider, req: DataRequest, report: &mut VerifyReport) {
+    report.entries_checked += 1;
+    if let Some(expected) = provider.get_recorded_hash(&req) {
+        // Errors finding or reading the data file are reported when loading below.
+        if let Ok((path, bytes)) = provider.read_data_file(&req) {
+            report.checksums_checked += 1;
+            let actual = manifest::get_hash(&bytes);
+            if actual != expected {
+                report.problems.push(Problem::ChecksumMismatch {
+                    path,
+                    expected: expected.to_string(),
+                    actual,
+                });
+            }
+        }
+    }
+    let response = match provider.load(&req) {
+        Ok(response) => response,
+        Err(err) => {
+            report.problems.push(Problem::LoadError(req, err));
+            return;
+        }
+    };
+    if let Ok(plural_rules) = response.borrow_payload::<PluralRuleStringsV1>() {
+        let rule_strings = [
+            ("zero", &plural_rules.zero),
+            ("one", &plural_rules.one),
+            ("two", &plural_rules.two),
+            ("few", &plural_rules.few),
+            ("many", &plural_rules.many),
+        ];
+        for (category, rule) in rule_strings.iter() {
+            let rule = match rule {
+                Some(rule) => rule,
+                None => continue,
+            };
+            // parse_condition stops at the samples, which start with '@'.
+            if let Err(error) = rules::parse_condition(rule.as_bytes()) {
+                report.problems.push(Problem::InvalidPluralRule {
+                    request: req.clone(),
+                    category,
+                    rule: rule.to_string(),
+                    error: format!("{:?}", error),
+                });
+            }
+        }
+    }
+}
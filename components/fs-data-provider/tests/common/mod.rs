@@ -0,0 +1,98 @@
+//! Helpers shared by the integration tests that export data.
+
+// Each test crate uses a different subset of the helpers.
+#![allow(dead_code)]
+
+use icu_data_provider::iter::{DataEntryCollection, IterableDataProvider};
+use icu_data_provider::prelude::*;
+use icu_fs_data_provider::export::fs_exporter::{ExporterOptions, FilesystemExporter};
+use icu_fs_data_provider::export::serializers::{self, Serializer};
+use icu_fs_data_provider::FsDataProvider;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The testdata directory, with plural rules from CLDR 37.
+pub const TESTDATA_ROOT: &str = "tests/testdata/json_plurals_37";
+
+pub fn testdata_provider() -> FsDataProvider {
+    FsDataProvider::try_new(TESTDATA_ROOT).expect("Loading file from testdata directory")
+}
+
+/// A directory for the output of a test, which is removed on drop, including when the test fails.
+///
+/// The directory name includes the process ID, so that concurrent test runs do not collide.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("icu4x_{}_{}", name, std::process::id()));
+        // The directory may be left over from a killed process with the same ID.
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).expect("Should create the test directory");
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+pub fn json_serializer() -> Box<dyn Serializer> {
+    Box::new(serializers::JsonSerializer::new(
+        &serializers::JsonSerializerOptions::default(),
+    ))
+}
+
+/// Exports the given keys with a FilesystemExporter, flushing after each key, and commits the
+/// export to `options.root`.
+pub fn export_keys<'d, P>(
+    source: &P,
+    keys: &[DataKey],
+    serializer: Box<dyn Serializer>,
+    options: &ExporterOptions,
+) where
+    P: IterableDataProvider<'d>,
+{
+    let mut exporter = FilesystemExporter::try_new(serializer, options)
+        .expect("Should successfully initialize data output directory");
+    for data_key in keys.iter() {
+        source
+            .export_key(data_key, &mut exporter)
+            .expect("Should successfully export");
+        exporter.flush().expect("Should successfully flush");
+    }
+    exporter.commit().expect("Should successfully commit");
+}
+
+/// Checks that the provider has the same DataEntries and data as the source for the given keys.
+pub fn check_same_data<'s, 'p, S, P>(source: &S, provider: &P, keys: &[DataKey])
+where
+    S: DataProvider<'s> + DataEntryCollection,
+    P: DataProvider<'p> + DataEntryCollection,
+{
+    for data_key in keys.iter() {
+        let expected: Vec<DataEntry> = source.iter_for_key(data_key).unwrap().collect();
+        let actual: Vec<DataEntry> = provider.iter_for_key(data_key).unwrap().collect();
+        assert_eq!(expected, actual, "{}", data_key);
+        for data_entry in expected {
+            let req = DataRequest {
+                data_key: *data_key,
+                data_entry,
+            };
+            let expected = source.load(&req).unwrap();
+            let actual = provider.load(&req).expect("Should read the exported entry");
+            assert_eq!(
+                serde_json::to_string(expected.borrow_as_serialize()).unwrap(),
+                serde_json::to_string(actual.borrow_as_serialize()).unwrap(),
+                "{}",
+                req
+            );
+        }
+    }
+}
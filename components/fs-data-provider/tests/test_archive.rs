@@ -0,0 +1,143 @@
+#![cfg(all(feature = "export", any(feature = "zip", feature = "tar")))]
+
+mod common;
+
+use icu_data_provider::iter::IterableDataProvider;
+use icu_fs_data_provider::export::{fs_exporter, ArchiveExporter};
+use icu_fs_data_provider::manifest::{AliasOption, ArchiveFormat};
+use icu_fs_data_provider::FsDataProvider;
+use std::fs;
+use std::path::PathBuf;
+
+/// Exports the testdata into an archive with an alias table, and reads it back.
+fn check_archive_round_trip(format: ArchiveFormat, file_name: &str) {
+    let source = common::testdata_provider();
+    let keys = source.supported_keys().unwrap();
+    let temp_dir = common::TempDir::new("archive_round_trip");
+    let archive_path = temp_dir.path().join(file_name);
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = archive_path.clone();
+    options.aliasing = AliasOption::AliasTable;
+    let export = |options: &fs_exporter::ExporterOptions| {
+        let mut exporter = ArchiveExporter::try_new(common::json_serializer(), format, options)?;
+        for data_key in keys.iter() {
+            source
+                .export_key(data_key, &mut exporter)
+                .expect("Should successfully export");
+            exporter.flush()?;
+        }
+        exporter.commit()
+    };
+    export(&options).expect("Should successfully write the archive");
+    let archive_bytes = fs::read(&archive_path).unwrap();
+
+    // The archive exists, and replacing it produces the same archive.
+    assert!(export(&options).is_err());
+    options.overwrite = fs_exporter::OverwriteOption::RemoveAndReplace;
+    export(&options).expect("Should successfully replace the archive");
+    assert_eq!(archive_bytes, fs::read(&archive_path).unwrap());
+    let mut dir_name = std::ffi::OsString::from(".");
+    dir_name.push(file_name);
+    dir_name.push(".contents");
+    assert!(!archive_path.with_file_name(dir_name).exists());
+
+    let provider = FsDataProvider::try_new_archive(archive_path).expect("Should read the archive");
+    assert_eq!(keys, provider.supported_keys().unwrap());
+    common::check_same_data(&source, &provider, &keys);
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn test_zip_round_trip() {
+    check_archive_round_trip(ArchiveFormat::Zip, "icu4x_archive.zip");
+}
+
+#[cfg(feature = "tar")]
+#[test]
+fn test_tar_round_trip() {
+    check_archive_round_trip(ArchiveFormat::Tar, "icu4x_archive.tar");
+}
+
+/// Archives made with `tar` have no inventory unless the directory had one, and may have a
+/// leading "./" in their file names.
+#[cfg(feature = "tar")]
+#[test]
+fn test_tar_without_inventory() {
+    use icu_data_provider::prelude::*;
+
+    let temp_dir = common::TempDir::new("archive_no_inventory");
+    let archive_path = temp_dir.path().join("data.tar");
+    let mut builder = tar::Builder::new(fs::File::create(&archive_path).unwrap());
+    builder.append_dir_all(".", common::TESTDATA_ROOT).unwrap();
+    builder.finish().unwrap();
+
+    let source = common::testdata_provider();
+    let keys = source.supported_keys().unwrap();
+    let provider = FsDataProvider::try_new_archive(archive_path).expect("Should read the archive");
+    assert_eq!(keys, provider.supported_keys().unwrap());
+    common::check_same_data(&source, &provider, &keys);
+
+    let req: DataRequest = "plurals/cardinal@1/fr".parse().unwrap();
+    assert!(matches!(
+        provider.load(&req),
+        Err(DataError::UnavailableEntry(_))
+    ));
+    let req: DataRequest = "decimal/symbols@1/en".parse().unwrap();
+    assert!(matches!(
+        provider.load(&req),
+        Err(DataError::UnsupportedCategory(_))
+    ));
+}
+
+#[test]
+fn test_unknown_archive_format() {
+    let result = FsDataProvider::try_new_archive(PathBuf::from("data.7z"));
+    assert!(result.is_err());
+}
+
+/// Reads an archive with non-blocking loads, which read the archive on a thread pool.
+#[cfg(all(feature = "async", feature = "zip"))]
+#[test]
+fn test_archive_async() {
+    use futures::executor::block_on;
+    use icu_data_provider::prelude::*;
+
+    let source = common::testdata_provider();
+    let keys = source.supported_keys().unwrap();
+    let temp_dir = common::TempDir::new("archive_async");
+    let archive_path = temp_dir.path().join("data.zip");
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = archive_path.clone();
+    let mut exporter =
+        ArchiveExporter::try_new(common::json_serializer(), ArchiveFormat::Zip, &options)
+            .expect("Should successfully initialize the archive");
+    for data_key in keys.iter() {
+        source
+            .export_key(data_key, &mut exporter)
+            .expect("Should successfully export");
+    }
+    exporter
+        .commit()
+        .expect("Should successfully write the archive");
+
+    let provider = FsDataProvider::try_new_archive(archive_path).expect("Should read the archive");
+    let req: DataRequest = "plurals/cardinal@1/sr".parse().unwrap();
+    let expected = provider.load(&req).unwrap();
+    let actual = block_on(provider.load_async(&req)).expect("Should read the entry");
+    assert_eq!(
+        serde_json::to_string(expected.borrow_as_serialize()).unwrap(),
+        serde_json::to_string(actual.borrow_as_serialize()).unwrap()
+    );
+    assert_eq!(expected.metadata, actual.metadata);
+
+    let req: DataRequest = "plurals/cardinal@1/fr".parse().unwrap();
+    assert!(matches!(
+        block_on(provider.load_async(&req)),
+        Err(DataError::UnavailableEntry(_))
+    ));
+    let req: DataRequest = "decimal/symbols@1/en".parse().unwrap();
+    assert!(matches!(
+        block_on(provider.load_async(&req)),
+        Err(DataError::UnsupportedCategory(_))
+    ));
+}
@@ -3,6 +3,9 @@ use icu_data_provider::structs;
 use icu_fs_data_provider::FsDataProvider;
 use std::borrow::Cow;
 
+#[cfg(feature = "export")]
+mod common;
+
 #[test]
 fn test_read_json() {
     let provider = FsDataProvider::try_new("tests/testdata/json_plurals_37")
@@ -17,6 +20,16 @@ fn test_read_json() {
             },
         })
         .expect("The key should be present in the testdata");
+    check_sr_plurals(&response);
+    assert_eq!(response.metadata.data_version.as_deref(), Some("37"));
+    let source = response
+        .metadata
+        .source
+        .expect("The file path should be set");
+    assert!(source.ends_with("sr.json"), "{}", source);
+}
+
+fn check_sr_plurals(response: &DataResponse) {
     let plurals_data: &structs::plurals::PluralRuleStringsV1 = response
         .borrow_payload()
         .expect("The JSON should match the struct definition");
@@ -31,3 +44,584 @@ fn test_read_json() {
         }
     );
 }
+
+#[cfg(feature = "async")]
+#[test]
+fn test_read_json_async() {
+    use futures::executor::block_on;
+
+    let provider = FsDataProvider::try_new("tests/testdata/json_plurals_37")
+        .expect("Loading file from testdata directory");
+    let make_request = |data_key, langid: &str| DataRequest {
+        data_key,
+        data_entry: DataEntry {
+            variant: None,
+            langid: langid.parse().expect("Valid language tag"),
+        },
+    };
+
+    let response =
+        block_on(provider.load_async(&make_request(icu_data_key!(plurals: cardinal@1), "sr")))
+            .expect("The key should be present in the testdata");
+    check_sr_plurals(&response);
+
+    assert!(matches!(
+        block_on(provider.load_async(&make_request(icu_data_key!(plurals: cardinal@1), "xx"))),
+        Err(DataError::UnavailableEntry(_))
+    ));
+    assert!(matches!(
+        block_on(provider.load_async(&make_request(icu_data_key!(plurals: cardinal@2), "sr"))),
+        Err(DataError::UnsupportedDataKey(_))
+    ));
+    assert!(matches!(
+        block_on(provider.load_async(&make_request(icu_data_key!(decimal: symbols@1), "sr"))),
+        Err(DataError::UnsupportedCategory(_))
+    ));
+}
+
+#[test]
+fn test_iter_for_key() {
+    use icu_data_provider::iter::DataEntryCollection;
+
+    let provider = FsDataProvider::try_new("tests/testdata/json_plurals_37")
+        .expect("Loading file from testdata directory");
+    let entries: Vec<String> = provider
+        .iter_for_key(&icu_data_key!(plurals: cardinal@1))
+        .expect("The key should be present in the testdata")
+        .map(|data_entry| data_entry.to_string())
+        .collect();
+    assert_eq!(entries, vec!["ar", "de", "en", "iw", "sr", "und", "zh"]);
+
+    assert!(matches!(
+        provider.iter_for_key(&icu_data_key!(plurals: cardinal@2)),
+        Err(DataError::UnsupportedDataKey(_))
+    ));
+    assert!(matches!(
+        provider.iter_for_key(&icu_data_key!(decimal: symbols@1)),
+        Err(DataError::UnsupportedCategory(_))
+    ));
+}
+
+#[test]
+fn test_supported_keys_without_inventory() {
+    let provider = FsDataProvider::try_new("tests/testdata/json_plurals_37")
+        .expect("Loading file from testdata directory");
+    assert_eq!(
+        provider.supported_keys().unwrap(),
+        vec![
+            icu_data_key!(plurals: cardinal@1),
+            icu_data_key!(plurals: ordinal@1)
+        ]
+    );
+    let entries = provider
+        .available_entries(&icu_data_key!(plurals: ordinal@1))
+        .unwrap();
+    assert_eq!(entries.len(), 7);
+}
+
+/// Checks that the manifest lists the exported data, and that FsDataProvider answers from it
+/// rather than from the files in the directory.
+#[cfg(feature = "export")]
+#[test]
+fn test_manifest_inventory() {
+    use icu_data_provider::InvariantDataProvider;
+    use icu_fs_data_provider::export::fs_exporter;
+    use sha2::{Digest, Sha256};
+
+    let temp_dir = common::TempDir::new("manifest_inventory");
+    let root = temp_dir.path().join("data");
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = root.clone();
+    options.data_version = Some("37".to_string());
+    common::export_keys(
+        &InvariantDataProvider,
+        &[structs::plurals::key::CARDINAL_V1],
+        common::json_serializer(),
+        &options,
+    );
+
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(root.join("manifest.json")).unwrap())
+            .unwrap();
+    let data_file = std::fs::read(root.join("plurals/cardinal@1/und.json")).unwrap();
+    let hash: String = Sha256::digest(&data_file)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    assert_eq!(
+        manifest["keys"],
+        serde_json::json!({ "plurals/cardinal@1": { "und": hash } })
+    );
+    assert_eq!(manifest["data_version"], "37");
+
+    // Files that are not in the manifest are ignored.
+    std::fs::create_dir_all(root.join("plurals/ordinal@1")).unwrap();
+    std::fs::copy(
+        root.join("plurals/cardinal@1/und.json"),
+        root.join("plurals/ordinal@1/und.json"),
+    )
+    .unwrap();
+    std::fs::copy(
+        root.join("plurals/cardinal@1/und.json"),
+        root.join("plurals/cardinal@1/en.json"),
+    )
+    .unwrap();
+
+    let provider = FsDataProvider::try_new(root).expect("Should read the export");
+    assert_eq!(
+        provider.supported_keys().unwrap(),
+        vec![structs::plurals::key::CARDINAL_V1]
+    );
+    assert_eq!(
+        provider
+            .available_entries(&structs::plurals::key::CARDINAL_V1)
+            .unwrap(),
+        vec!["und".parse::<DataEntry>().unwrap()]
+    );
+    let make_request = |data_key, langid: &str| DataRequest {
+        data_key,
+        data_entry: DataEntry {
+            variant: None,
+            langid: langid.parse().expect("Valid language tag"),
+        },
+    };
+    provider
+        .load(&make_request(structs::plurals::key::CARDINAL_V1, "und"))
+        .expect("The entry is in the manifest");
+    assert!(matches!(
+        provider.load(&make_request(structs::plurals::key::CARDINAL_V1, "en")),
+        Err(DataError::UnavailableEntry(_))
+    ));
+    assert!(matches!(
+        provider.load(&make_request(structs::plurals::key::ORDINAL_V1, "und")),
+        Err(DataError::UnsupportedDataKey(_))
+    ));
+    assert!(matches!(
+        provider.load(&make_request(structs::decimal::key::SYMBOLS_V1, "und")),
+        Err(DataError::UnsupportedCategory(_))
+    ));
+}
+
+/// Exports the testdata into a directory with each kind of aliases in turn, reading each
+/// directory back with FsDataProvider to export the next one, and finally without aliases.
+#[cfg(all(feature = "export", target_family = "unix"))]
+#[test]
+fn test_transcode_with_aliases() {
+    use icu_fs_data_provider::export::fs_exporter;
+    use icu_fs_data_provider::manifest::AliasOption;
+
+    let keys = [
+        icu_data_key!(plurals: cardinal@1),
+        icu_data_key!(plurals: ordinal@1),
+    ];
+    let temp_dir = common::TempDir::new("transcode");
+    let source = common::testdata_provider();
+    let mut roots = vec![];
+    let mut providers: Vec<FsDataProvider> = vec![];
+    for (dir_name, aliasing) in [
+        ("symlink", AliasOption::Symlink),
+        ("hardlink", AliasOption::HardLink),
+        ("table", AliasOption::AliasTable),
+        ("plain", AliasOption::NoAliases),
+    ]
+    .iter()
+    {
+        let root = temp_dir.path().join(dir_name);
+        let mut options = fs_exporter::ExporterOptions::default();
+        options.root = root.clone();
+        options.aliasing = *aliasing;
+        common::export_keys(
+            providers.last().unwrap_or(&source),
+            &keys,
+            common::json_serializer(),
+            &options,
+        );
+        providers.push(FsDataProvider::try_new(root.clone()).expect("Should read the export"));
+        roots.push(root);
+    }
+
+    // In the testdata, "de" and "en" have the same cardinal rules.
+    use std::os::unix::fs::MetadataExt;
+    let hardlink_root = &roots[1];
+    let metadata = std::fs::metadata(hardlink_root.join("plurals/cardinal@1/en.json")).unwrap();
+    assert_eq!(metadata.nlink(), 2);
+    let table_root = &roots[2];
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(table_root.join("manifest.json")).unwrap())
+            .unwrap();
+    assert_eq!(manifest["aliases"]["plurals/cardinal@1"]["en"], "de");
+    assert!(!table_root.join("plurals/cardinal@1/en.json").exists());
+
+    for provider in providers.iter() {
+        common::check_same_data(&source, provider, &keys);
+    }
+}
+
+/// Checks the DryRunExporter report against the files written by an actual export.
+#[cfg(all(feature = "export", target_family = "unix"))]
+#[test]
+fn test_dry_run_report() {
+    use icu_fs_data_provider::manifest::CompressionOption;
+
+    check_dry_run_report(CompressionOption::None, "json");
+    #[cfg(feature = "flate2")]
+    check_dry_run_report(CompressionOption::Gzip, "gz");
+}
+
+/// Checks the report of a DryRunExporter with the given compression against the data files,
+/// with the given extension, written by an actual export with the same compression.
+#[cfg(all(feature = "export", target_family = "unix"))]
+fn check_dry_run_report(
+    compression: icu_fs_data_provider::manifest::CompressionOption,
+    extension: &str,
+) {
+    use icu_fs_data_provider::export::{fs_exporter, DryRunExporter};
+    use icu_fs_data_provider::manifest::AliasOption;
+
+    let source = common::testdata_provider();
+    let keys = source.supported_keys().unwrap();
+
+    let mut exporter =
+        DryRunExporter::try_new_with_compression(common::json_serializer(), compression)
+            .expect("Compression should be enabled");
+    for data_key in keys.iter() {
+        let result = exporter.export_key(&source, data_key);
+        exporter.record_result(data_key, result);
+    }
+    let decimal_key = icu_data_key!(decimal: symbols@1);
+    let result = exporter.export_key(&source, &decimal_key);
+    exporter.record_result(&decimal_key, result);
+    let report = exporter.close();
+
+    let temp_dir = common::TempDir::new(&format!("dry_run_{}", extension));
+    let root = temp_dir.path().join("data");
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = root.clone();
+    options.aliasing = AliasOption::Symlink;
+    options.compression = compression;
+    common::export_keys(&source, &keys, common::json_serializer(), &options);
+
+    for data_key in keys.iter() {
+        let key_report = &report.keys[&data_key.to_string()];
+        let mut key_path = root.clone();
+        key_path.extend(data_key.get_components().iter());
+        let data_file_sizes: Vec<u64> = std::fs::read_dir(&key_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension() == Some(std::ffi::OsStr::new(extension)))
+            .map(|path| std::fs::metadata(path).unwrap().len())
+            .collect();
+        assert_eq!(key_report.entries, 7, "{}", data_key);
+        assert_eq!(
+            key_report.entries - key_report.duplicates,
+            data_file_sizes.len(),
+            "{}",
+            data_key
+        );
+        assert_eq!(
+            key_report.bytes - key_report.duplicate_bytes,
+            data_file_sizes.iter().sum::<u64>(),
+            "{}",
+            data_key
+        );
+        assert!(key_report.errors.is_empty(), "{}", data_key);
+    }
+    assert_eq!(
+        report.keys["decimal/symbols@1"].errors,
+        vec!["Unsupported category: decimal"]
+    );
+    assert_eq!(report.total().entries, 14);
+}
+
+/// Checks that the DryRunExporter reports every entry that fails to load and counts the others.
+#[cfg(feature = "export")]
+#[test]
+fn test_dry_run_errors() {
+    use icu_data_provider::iter::DataEntryCollection;
+    use icu_fs_data_provider::export::DryRunExporter;
+
+    /// Fails to load the entries for the languages starting with "s" or "z".
+    struct FailingProvider(FsDataProvider);
+
+    impl<'d> DataProvider<'d> for FailingProvider {
+        fn load(&self, req: &DataRequest) -> Result<DataResponse<'d>, DataError> {
+            if req
+                .data_entry
+                .langid
+                .language
+                .as_str()
+                .starts_with(&['s', 'z'][..])
+            {
+                return Err(DataError::UnavailableEntry(req.clone()));
+            }
+            self.0.load(req)
+        }
+    }
+
+    impl DataEntryCollection for FailingProvider {
+        fn iter_for_key(
+            &self,
+            data_key: &DataKey,
+        ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {
+            self.0.iter_for_key(data_key)
+        }
+    }
+
+    let provider = FailingProvider(common::testdata_provider());
+    let data_key = icu_data_key!(plurals: cardinal@1);
+    let mut exporter = DryRunExporter::new(common::json_serializer());
+    let result = exporter.export_key(&provider, &data_key);
+    exporter.record_result(&data_key, result);
+    let report = exporter.close();
+
+    let key_report = &report.keys["plurals/cardinal@1"];
+    assert_eq!(key_report.entries, 5);
+    let mut errors = key_report.errors.clone();
+    errors.sort();
+    assert_eq!(
+        errors,
+        vec![
+            "plurals/cardinal@1/sr: Unavailable data entry: plurals/cardinal@1/sr",
+            "plurals/cardinal@1/zh: Unavailable data entry: plurals/cardinal@1/zh",
+        ]
+    );
+}
+
+/// Checks that the output directory is only replaced when the export is committed, and that the
+/// partial export is removed either way.
+#[cfg(feature = "export")]
+#[test]
+fn test_export_commit() {
+    use icu_data_provider::iter::IterableDataProvider;
+    use icu_data_provider::InvariantDataProvider;
+    use icu_fs_data_provider::export::fs_exporter;
+
+    let temp_dir = common::TempDir::new("export_commit");
+    let root = temp_dir.path().join("data");
+    let partial_root = temp_dir.path().join(".data.partial");
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(root.join("old.txt"), "old").unwrap();
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = root.clone();
+
+    // A non-empty output directory is rejected before anything is written.
+    assert!(fs_exporter::FilesystemExporter::try_new(common::json_serializer(), &options).is_err());
+
+    // Without a commit, the output directory is untouched and the partial export is removed.
+    options.overwrite = fs_exporter::OverwriteOption::RemoveAndReplace;
+    let mut exporter =
+        fs_exporter::FilesystemExporter::try_new(common::json_serializer(), &options)
+            .expect("Should successfully initialize data output directory");
+    InvariantDataProvider
+        .export_key(&structs::plurals::key::CARDINAL_V1, &mut exporter)
+        .expect("Should successfully export");
+    exporter.flush().expect("Should successfully flush");
+    assert!(partial_root.join("manifest.json").exists());
+    assert!(!root.join("manifest.json").exists());
+    drop(exporter);
+    assert!(!partial_root.exists());
+    assert!(root.join("old.txt").exists());
+
+    // On commit, the output directory is replaced.
+    common::export_keys(
+        &InvariantDataProvider,
+        &[structs::plurals::key::CARDINAL_V1],
+        common::json_serializer(),
+        &options,
+    );
+    assert!(!partial_root.exists());
+    assert!(!root.join("old.txt").exists());
+    assert!(root.join("manifest.json").exists());
+    assert!(root.join("plurals/cardinal@1/und.json").exists());
+}
+
+/// Exports the testdata with the given compression, and checks that it is read back the same.
+#[cfg(all(feature = "export", any(feature = "flate2", feature = "zstd")))]
+fn check_compressed_round_trip(
+    compression: icu_fs_data_provider::manifest::CompressionOption,
+    file_name: &str,
+) {
+    use icu_fs_data_provider::export::fs_exporter;
+
+    let source = common::testdata_provider();
+    let temp_dir = common::TempDir::new("export_compressed");
+    let root = temp_dir.path().join("data");
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = root.clone();
+    options.compression = compression;
+    let keys = [structs::plurals::key::CARDINAL_V1];
+    common::export_keys(&source, &keys, common::json_serializer(), &options);
+    assert!(root.join("plurals/cardinal@1").join(file_name).exists());
+
+    let compressed = FsDataProvider::try_new(root).expect("Should read the export");
+    common::check_same_data(&source, &compressed, &keys);
+}
+
+#[cfg(all(feature = "export", feature = "flate2"))]
+#[test]
+fn test_export_gzip() {
+    check_compressed_round_trip(
+        icu_fs_data_provider::manifest::CompressionOption::Gzip,
+        "sr.json.gz",
+    );
+}
+
+#[cfg(all(feature = "export", feature = "zstd"))]
+#[test]
+fn test_export_zstd() {
+    check_compressed_round_trip(
+        icu_fs_data_provider::manifest::CompressionOption::Zstd,
+        "sr.json.zst",
+    );
+}
+
+/// Exports every key in the `structs` registry with the given serializer, reads it back with
+/// FsDataProvider, and checks that the data is unchanged.
+#[cfg(feature = "export")]
+fn check_export_round_trip(
+    serializer: Box<dyn icu_fs_data_provider::export::serializers::Serializer>,
+    dir_name: &str,
+) {
+    use icu_data_provider::InvariantDataProvider;
+    use icu_fs_data_provider::export::fs_exporter;
+
+    let temp_dir = common::TempDir::new(dir_name);
+    let root = temp_dir.path().join("data");
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = root.clone();
+    let keys = structs::get_all_keys();
+    common::export_keys(&InvariantDataProvider, &keys, serializer, &options);
+
+    let fs_provider = FsDataProvider::try_new(root).expect("Should read the export");
+    common::check_same_data(&InvariantDataProvider, &fs_provider, &keys);
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_export_round_trip_json() {
+    check_export_round_trip(common::json_serializer(), "json_round_trip");
+}
+
+#[cfg(all(feature = "export", feature = "bincode"))]
+#[test]
+fn test_export_round_trip_bincode() {
+    use icu_fs_data_provider::export::serializers;
+    check_export_round_trip(
+        Box::new(serializers::BincodeSerializer::new()),
+        "bincode_round_trip",
+    );
+}
+
+#[cfg(all(feature = "export", feature = "postcard"))]
+#[test]
+fn test_export_round_trip_postcard() {
+    use icu_fs_data_provider::export::serializers;
+    check_export_round_trip(
+        Box::new(serializers::PostcardSerializer::new()),
+        "postcard_round_trip",
+    );
+}
+
+/// Generated by `test_rust_export` below; compiled here to check that the output is valid.
+#[cfg(feature = "export")]
+#[rustfmt::skip]
+#[path = "testdata/baked/mod.rs"]
+mod baked;
+
+/// Exports the invariant data for all keys, plus plural rules for "sr", as Rust source code.
+#[cfg(feature = "export")]
+fn export_rust_source() -> String {
+    use icu_data_provider::iter::{DataExporter, IterableDataProvider};
+    use icu_data_provider::InvariantDataProvider;
+    use icu_fs_data_provider::export::rust_exporter::{RustExporter, RustExporterOptions};
+
+    let mut options = RustExporterOptions::default();
+    options.data_version = Some("37".to_string());
+    let mut exporter = RustExporter::new(Vec::new(), &options);
+    for data_key in structs::get_all_keys() {
+        InvariantDataProvider
+            .export_key(&data_key, &mut exporter)
+            .expect("Should successfully export");
+    }
+    let provider = FsDataProvider::try_new("tests/testdata/json_plurals_37")
+        .expect("Loading file from testdata directory");
+    let req = DataRequest {
+        data_key: structs::plurals::key::CARDINAL_V1,
+        data_entry: DataEntry {
+            variant: None,
+            langid: "sr".parse().unwrap(),
+        },
+    };
+    let response = provider.load(&req).unwrap();
+    exporter
+        .put(&req, response.borrow_as_serialize())
+        .expect("Should successfully export");
+    let source = exporter
+        .close()
+        .expect("Should successfully write the module");
+    String::from_utf8(source).unwrap()
+}
+
+#[cfg(feature = "export")]
+#[test]
+fn test_rust_export() {
+    use icu_data_provider::iter::DataEntryCollection;
+
+    let path = "tests/testdata/baked/mod.rs";
+    let source = export_rust_source();
+    if std::env::var_os("ICU4X_UPDATE_BAKED").is_some() {
+        std::fs::write(path, &source).unwrap();
+    }
+    assert_eq!(
+        std::fs::read_to_string(path).unwrap(),
+        source,
+        "The generated module is out of date; rerun with ICU4X_UPDATE_BAKED=1 to update it"
+    );
+
+    // The generated module serves the same data as the source.
+    let provider = baked::BakedDataProvider;
+    let response = provider
+        .load(&DataRequest {
+            data_key: structs::plurals::key::CARDINAL_V1,
+            data_entry: DataEntry {
+                variant: None,
+                langid: "sr".parse().unwrap(),
+            },
+        })
+        .expect("The generated module should contain the data");
+    check_sr_plurals(&response);
+    assert_eq!(response.metadata.data_version.as_deref(), Some("37"));
+    let entries: Vec<String> = provider
+        .iter_for_key(&structs::plurals::key::CARDINAL_V1)
+        .unwrap()
+        .map(|entry| entry.to_string())
+        .collect();
+    assert_eq!(entries, vec!["sr", "und"]);
+    assert!(matches!(
+        provider.load(&DataRequest {
+            data_key: structs::plurals::key::CARDINAL_V1,
+            data_entry: DataEntry {
+                variant: None,
+                langid: "fr".parse().unwrap(),
+            },
+        }),
+        Err(DataError::UnavailableEntry(_))
+    ));
+    for data_key in structs::get_all_keys() {
+        assert!(provider.iter_for_key(&data_key).is_ok(), "{}", data_key);
+    }
+
+    // Payloads that are not constant expressions are constructed once, and then borrowed.
+    let req = DataRequest {
+        data_key: structs::decimal::key::SYMBOLS_V1,
+        data_entry: DataEntry {
+            variant: None,
+            langid: "und".parse().unwrap(),
+        },
+    };
+    let first = provider.load(&req).unwrap();
+    let second = provider.load(&req).unwrap();
+    let first: &structs::decimal::SymbolsV1 = first.borrow_payload().unwrap();
+    let second: &structs::decimal::SymbolsV1 = second.borrow_payload().unwrap();
+    assert!(std::ptr::eq(first, second));
+}
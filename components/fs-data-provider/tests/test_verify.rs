@@ -0,0 +1,109 @@
+#![cfg(all(feature = "verify", feature = "export"))]
+
+mod common;
+
+use icu_data_provider::prelude::*;
+use icu_data_provider::structs;
+use icu_fs_data_provider::export::fs_exporter;
+use icu_fs_data_provider::verify::{self, Problem};
+use icu_fs_data_provider::FsDataProvider;
+use std::fs;
+
+#[test]
+fn test_verify() {
+    let temp_dir = common::TempDir::new("verify");
+    let root = temp_dir.path().join("data");
+
+    // Transcode the testdata, so that the manifest has checksums.
+    let source = common::testdata_provider();
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = root.clone();
+    common::export_keys(
+        &source,
+        &source.supported_keys().unwrap(),
+        common::json_serializer(),
+        &options,
+    );
+
+    let provider = FsDataProvider::try_new(root.clone()).expect("Should read the export");
+    let report = verify::verify(&provider);
+    assert!(report.problems.is_empty(), "{:?}", report.problems);
+    assert_eq!(report.entries_checked, 14);
+    assert_eq!(report.checksums_checked, 14);
+
+    // Truncate one file, delete another, and break a plural rule in a third.
+    let cardinal_path = root.join("plurals/cardinal@1");
+    let en = fs::read(cardinal_path.join("en.json")).unwrap();
+    fs::write(cardinal_path.join("en.json"), &en[..en.len() / 2]).unwrap();
+    fs::remove_file(cardinal_path.join("de.json")).unwrap();
+    let sr = fs::read_to_string(cardinal_path.join("sr.json")).unwrap();
+    fs::write(
+        cardinal_path.join("sr.json"),
+        sr.replace("v = 0 and i % 10 = 1", "v = 0 and i %"),
+    )
+    .unwrap();
+
+    let report = verify::verify(&provider);
+    assert_eq!(report.entries_checked, 14);
+    assert_eq!(report.checksums_checked, 13);
+    let problems: Vec<String> = report.problems.iter().map(|p| p.to_string()).collect();
+    assert_eq!(report.problems.len(), 5, "{:?}", problems);
+    assert!(matches!(
+        &report.problems[0],
+        Problem::LoadError(req, DataError::ResourceError(_)) if req.data_entry.langid == "de"
+    ));
+    assert!(matches!(
+        &report.problems[1],
+        Problem::ChecksumMismatch { path, .. } if path.ends_with("en.json")
+    ));
+    assert!(matches!(
+        &report.problems[2],
+        Problem::LoadError(req, DataError::ResourceError(_)) if req.data_entry.langid == "en"
+    ));
+    assert!(matches!(
+        &report.problems[3],
+        Problem::ChecksumMismatch { path, .. } if path.ends_with("sr.json")
+    ));
+    assert!(matches!(
+        &report.problems[4],
+        Problem::InvalidPluralRule {
+            category: "one",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_verify_unknown_key() {
+    let temp_dir = common::TempDir::new("verify_unknown_key");
+    let root = temp_dir.path().join("data");
+    let mut options = fs_exporter::ExporterOptions::default();
+    options.root = root.clone();
+    common::export_keys(
+        &icu_data_provider::InvariantDataProvider,
+        &[structs::plurals::key::CARDINAL_V1],
+        common::json_serializer(),
+        &options,
+    );
+
+    // Data for a key that is not in the registry cannot be deserialized.
+    fs::rename(
+        root.join("plurals/cardinal@1"),
+        root.join("plurals/cardinal@9"),
+    )
+    .unwrap();
+    let manifest = fs::read_to_string(root.join("manifest.json")).unwrap();
+    fs::write(
+        root.join("manifest.json"),
+        manifest.replace("cardinal@1", "cardinal@9"),
+    )
+    .unwrap();
+
+    let provider = FsDataProvider::try_new(root.clone()).expect("Should read the export");
+    let report = verify::verify(&provider);
+    assert_eq!(report.entries_checked, 1);
+    assert!(matches!(
+        report.problems.as_slice(),
+        [Problem::LoadError(_, DataError::UnsupportedDataKey(_))]
+    ));
+}
@@ -0,0 +1,115 @@
+// @generated by icu_fs_data_provider::export::RustExporter. Do not edit.
+
+use icu_data_provider::iter::DataEntryCollection;
+use icu_data_provider::prelude::*;
+
+const KEY_DECIMAL_SYMBOLS_1: DataKey = DataKey::new(DataCategory::Decimal, "symbols", 1);
+const KEY_FALLBACK_PARENTS_1: DataKey = DataKey::new(DataCategory::Fallback, "parents", 1);
+const KEY_PLURALS_CARDINAL_1: DataKey = DataKey::new(DataCategory::Plurals, "cardinal", 1);
+const KEY_PLURALS_ORDINAL_1: DataKey = DataKey::new(DataCategory::Plurals, "ordinal", 1);
+
+/// Data categories with at least one data key in this module.
+const CATEGORIES: &[DataCategory] = &[DataCategory::Decimal, DataCategory::Fallback, DataCategory::Plurals];
+
+/// Data for `decimal/symbols@1/und`.
+static DATA_DECIMAL_SYMBOLS_1_UND: ::std::sync::LazyLock<::icu_data_provider::structs::decimal::SymbolsV1> =
+    ::std::sync::LazyLock::new(|| ::icu_data_provider::structs::decimal::SymbolsV1 { zero_digit: '0', decimal_separator: ".".into(), grouping_separator: ",".into() });
+
+/// Data for `fallback/parents@1/und`.
+static DATA_FALLBACK_PARENTS_1_UND: ::std::sync::LazyLock<::icu_data_provider::structs::fallback::ParentLocalesV1> =
+    ::std::sync::LazyLock::new(|| ::icu_data_provider::structs::fallback::ParentLocalesV1 { parents: vec![] });
+
+/// Data for `plurals/cardinal@1/sr`.
+static DATA_PLURALS_CARDINAL_1_SR: ::icu_data_provider::structs::plurals::PluralRuleStringsV1 = ::icu_data_provider::structs::plurals::PluralRuleStringsV1 { zero: None, one: Some(::std::borrow::Cow::Borrowed("v = 0 and i % 10 = 1 and i % 100 != 11 or f % 10 = 1 and f % 100 != 11 @integer 1, 21, 31, 41, 51, 61, 71, 81, 101, 1001, … @decimal 0.1, 1.1, 2.1, 3.1, 4.1, 5.1, 6.1, 7.1, 10.1, 100.1, 1000.1, …")), two: None, few: Some(::std::borrow::Cow::Borrowed("v = 0 and i % 10 = 2..4 and i % 100 != 12..14 or f % 10 = 2..4 and f % 100 != 12..14 @integer 2~4, 22~24, 32~34, 42~44, 52~54, 62, 102, 1002, … @decimal 0.2~0.4, 1.2~1.4, 2.2~2.4, 3.2~3.4, 4.2~4.4, 5.2, 10.2, 100.2, 1000.2, …")), many: None };
+
+/// Data for `plurals/cardinal@1/und`.
+static DATA_PLURALS_CARDINAL_1_UND: ::icu_data_provider::structs::plurals::PluralRuleStringsV1 = ::icu_data_provider::structs::plurals::PluralRuleStringsV1 { zero: None, one: None, two: None, few: None, many: None };
+
+/// Data for `plurals/ordinal@1/und`.
+static DATA_PLURALS_ORDINAL_1_UND: ::icu_data_provider::structs::plurals::PluralRuleStringsV1 = ::icu_data_provider::structs::plurals::PluralRuleStringsV1 { zero: None, one: None, two: None, few: None, many: None };
+
+/// Returns whether the DataEntry has exactly the given language variants.
+#[allow(dead_code)]
+fn has_variants(data_entry: &DataEntry, variants: &[&str]) -> bool {
+    data_entry
+        .langid
+        .variants
+        .iter()
+        .map(|variant| variant.as_str())
+        .eq(variants.iter().copied())
+}
+
+/// A data provider serving data compiled into the binary.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct BakedDataProvider;
+
+impl BakedDataProvider {
+    /// Returns the error for a data key that is not in this module.
+    fn unsupported_key_error(data_key: &DataKey) -> DataError {
+        if CATEGORIES.contains(&data_key.category) {
+            DataError::UnsupportedDataKey(*data_key)
+        } else {
+            DataError::UnsupportedCategory(data_key.category)
+        }
+    }
+}
+
+impl DataProvider<'static> for BakedDataProvider {
+    #[allow(unreachable_code, unused_variables)]
+    fn load(&self, req: &DataRequest) -> Result<DataResponse<'static>, DataError> {
+        let builder = DataResponseBuilder {
+            data_langid: req.data_entry.langid.clone(),
+        };
+        // The variant, language, script, and region of the DataEntry, to match without
+        // allocating. Language variants are checked separately.
+        let langid = &req.data_entry.langid;
+        let subtags = (
+            req.data_entry.variant.as_deref(),
+            langid.language.as_str(),
+            langid.script.as_ref().map(|script| script.as_str()),
+            langid.region.as_ref().map(|region| region.as_str()),
+        );
+        let mut response = match req.data_key {
+            KEY_DECIMAL_SYMBOLS_1 => match subtags {
+                (None, "und", None, None) if has_variants(&req.data_entry, &[]) => builder.with_borrowed_payload(&*DATA_DECIMAL_SYMBOLS_1_UND),
+                _ => return Err(DataError::UnavailableEntry(req.clone())),
+            },
+            KEY_FALLBACK_PARENTS_1 => match subtags {
+                (None, "und", None, None) if has_variants(&req.data_entry, &[]) => builder.with_borrowed_payload(&*DATA_FALLBACK_PARENTS_1_UND),
+                _ => return Err(DataError::UnavailableEntry(req.clone())),
+            },
+            KEY_PLURALS_CARDINAL_1 => match subtags {
+                (None, "sr", None, None) if has_variants(&req.data_entry, &[]) => builder.with_borrowed_payload(&DATA_PLURALS_CARDINAL_1_SR),
+                (None, "und", None, None) if has_variants(&req.data_entry, &[]) => builder.with_borrowed_payload(&DATA_PLURALS_CARDINAL_1_UND),
+                _ => return Err(DataError::UnavailableEntry(req.clone())),
+            },
+            KEY_PLURALS_ORDINAL_1 => match subtags {
+                (None, "und", None, None) if has_variants(&req.data_entry, &[]) => builder.with_borrowed_payload(&DATA_PLURALS_ORDINAL_1_UND),
+                _ => return Err(DataError::UnavailableEntry(req.clone())),
+            },
+            _ => return Err(Self::unsupported_key_error(&req.data_key)),
+        };
+        response.metadata.data_version = Some(::std::borrow::Cow::Borrowed("37"));
+        Ok(response)
+    }
+}
+
+impl DataEntryCollection for BakedDataProvider {
+    fn iter_for_key(
+        &self,
+        data_key: &DataKey,
+    ) -> Result<Box<dyn Iterator<Item = DataEntry>>, DataError> {
+        let entries: &[&str] = match *data_key {
+            KEY_DECIMAL_SYMBOLS_1 => &["und"],
+            KEY_FALLBACK_PARENTS_1 => &["und"],
+            KEY_PLURALS_CARDINAL_1 => &["sr", "und"],
+            KEY_PLURALS_ORDINAL_1 => &["und"],
+            _ => return Err(Self::unsupported_key_error(data_key)),
+        };
+        Ok(Box::new(
+            entries
+                .iter()
+                .map(|s| s.parse().expect("Generated data entries are valid")),
+        ))
+    }
+}